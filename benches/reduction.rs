@@ -0,0 +1,97 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lambda_calculus::combinators::Y;
+use lambda_calculus::data::num::church::{add, fac, is_zero, one, pred, quot, sub, succ, zero};
+use lambda_calculus::data::pair::{fst, pair, snd};
+use lambda_calculus::reduction::{normalize_memoized, normalize_shared};
+use lambda_calculus::*;
+
+fn factorial(c: &mut Criterion) {
+    let term = app(fac(), 6.into_church());
+    let mut group = c.benchmark_group("fac 6");
+
+    group.bench_function("beta", |b| b.iter(|| beta(black_box(term.clone()), NOR, 0)));
+    group.bench_function("normalize_shared", |b| {
+        b.iter(|| normalize_shared(black_box(term.clone()), 0))
+    });
+    group.finish();
+}
+
+fn quotient(c: &mut Criterion) {
+    let term = app!(quot(), 12.into_church(), 5.into_church());
+    let mut group = c.benchmark_group("quot 12 5");
+
+    group.bench_function("beta", |b| b.iter(|| beta(black_box(term.clone()), NOR, 0)));
+    group.bench_function("normalize_shared", |b| {
+        b.iter(|| normalize_shared(black_box(term.clone()), 0))
+    });
+    group.finish();
+}
+
+fn predecessor(c: &mut Criterion) {
+    // PAIR_PRED ≡ λn.FST (n (λp.PAIR (SND p) (SUCC (SND p))) (PAIR ZERO ZERO))
+    let pair_pred = abs(app(
+        fst(),
+        app!(
+            Var(1),
+            abs(app!(
+                pair(),
+                app(snd(), Var(1)),
+                app(succ(), app(snd(), Var(1)))
+            )),
+            app!(pair(), zero(), zero())
+        ),
+    ));
+    let mut group = c.benchmark_group("pred 50");
+
+    for (name, pred) in [("pred", pred()), ("pair pred", pair_pred)] {
+        let term = app(pred, 50.into_church());
+
+        group.bench_function(name, |b| b.iter(|| beta(black_box(term.clone()), NOR, 0)));
+    }
+    group.finish();
+
+    let term = app!(sub(), 30.into_church(), 15.into_church());
+    let mut group = c.benchmark_group("sub 30 15");
+
+    group.bench_function("beta", |b| b.iter(|| beta(black_box(term.clone()), NOR, 0)));
+    group.bench_function("normalize_shared", |b| {
+        b.iter(|| normalize_shared(black_box(term.clone()), 0))
+    });
+    group.finish();
+}
+
+fn naive_fibonacci(c: &mut Criterion) {
+    // FIB ≡ Y (λf.λn.IS_ZERO n ZERO (IS_ZERO (PRED n) ONE (ADD (f (PRED n)) (f (PRED (PRED n))))))
+    let fib = app(
+        Y(),
+        abs!(
+            2,
+            app!(
+                is_zero(),
+                Var(1),
+                zero(),
+                app!(
+                    is_zero(),
+                    app(pred(), Var(1)),
+                    one(),
+                    app!(
+                        add(),
+                        app(Var(2), app(pred(), Var(1))),
+                        app(Var(2), app(pred(), app(pred(), Var(1))))
+                    )
+                )
+            )
+        ),
+    );
+    let term = app(fib, 8.into_church());
+    let mut group = c.benchmark_group("naive fib 8");
+
+    group.bench_function("beta", |b| b.iter(|| beta(black_box(term.clone()), NOR, 0)));
+    group.bench_function("normalize_memoized", |b| {
+        b.iter(|| normalize_memoized(black_box(term.clone()), 0))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, factorial, quotient, predecessor, naive_fibonacci);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lambda_calculus::combinators::{I, S};
+use lambda_calculus::data::list::church::sort;
+use lambda_calculus::data::num::church::{fac, fib};
+use lambda_calculus::reduction::Order;
+use lambda_calculus::*;
+
+/// The reduction orders that produce normal forms.
+const NORMALIZING: [Order; 4] = [NOR, HNO, APP, HAP];
+
+fn bench_orders(c: &mut Criterion, name: &str, term: &Term, orders: &[Order]) {
+    let mut group = c.benchmark_group(name);
+
+    for &order in orders {
+        group.bench_function(order.to_string(), |b| {
+            b.iter(|| beta(black_box(term.clone()), order, 0))
+        });
+    }
+    group.finish();
+}
+
+fn factorial(c: &mut Criterion) {
+    bench_orders(c, "fac 5", &app(fac(), 5.into_church()), &NORMALIZING);
+}
+
+fn fibonacci(c: &mut Criterion) {
+    bench_orders(c, "fib 8", &app(fib(), 8.into_church()), &NORMALIZING);
+}
+
+fn list_sort(c: &mut Criterion) {
+    let term = app(sort(), vec![5, 3, 8, 1, 0, 2].into_church());
+
+    bench_orders(c, "sort 6", &term, &NORMALIZING);
+}
+
+fn self_interpreter(c: &mut Criterion) {
+    // John Tromp's self-interpreter for binary lambda calculus; applied to a continuation and a
+    // list of bits encoding a term it passes the decoded term to the continuation
+    let interpreter = parse(
+        "(λ1 1)(λλλ1(λλλλ3(λ5(3(λ2(3(λλ3(λ1 2 3)))(4(λ4(λ3 1(2 1))))))(1(2(λ1 2))\
+         (λ4(λ4(λ2(1 4)))5))))(3 3)2)",
+        DeBruijn,
+    )
+    .unwrap();
+    let bits: Vec<Term> = S()
+        .to_blc()
+        .chars()
+        .map(|bit| (bit == '0').into())
+        .collect();
+    let term = app!(interpreter, I(), bits.into_pair_list());
+
+    // the interpreter relies on self-application, so the applicative orders diverge
+    bench_orders(c, "blc self-interpreter S", &term, &[NOR, HNO]);
+}
+
+criterion_group!(benches, factorial, fibonacci, list_sort, self_interpreter);
+criterion_main!(benches);
@@ -8,6 +8,9 @@
 //! * the divergent combinator Ω
 //! * [the fixed-point combinators Y and Z](https://en.wikipedia.org/wiki/Fixed-point_combinator)
 //! * the reverse application (thrush) combinator R
+//! * Turing's fixed-point combinator Θ and its half, U
+//!
+//! It also provides `to_ski`, which translates lambda terms to the SKI combinator calculus.
 
 #![allow(non_snake_case)]
 
@@ -246,7 +249,9 @@ pub fn R() -> Term {
 /// Θ ≡ (λxy.y (x x y)) (λxy.y (x x y)) ≡ (λ λ 1 (2 2 1)) (λ λ 1 (2 2 1))
 ///
 /// It is suitable for `NOR` (normal), `HNO` (hybrid normal), `CBN` (call-by-name), and `HSP` (head
-/// spine) reduction `Order`s.
+/// spine) reduction `Order`s. Like `Y`, it doesn't work with the applicative-family (`APP`, `CBV`
+/// and `HAP`) reduction `Order`s: `Θ f` reduces to `f (Θ f)`, whose argument is evaluated before
+/// `f` can discard it, so the reduction never ends; the `Z` combinator needs to be used instead.
 ///
 /// # Example
 /// ```
@@ -260,9 +265,142 @@ pub fn R() -> Term {
 ///     beta(app(dummy(), app(T(), dummy())), NOR, 0)
 /// );
 /// ```
+#[doc(alias = "theta")]
 pub fn T() -> Term {
     app(
         abs!(2, app(Var(1), app!(Var(2), Var(2), Var(1)))),
         abs!(2, app(Var(1), app!(Var(2), Var(2), Var(1)))),
     )
 }
+
+/// U - the self-application half of Turing's fixed-point combinator; `U U` is `Θ`.
+///
+/// Unlike `Y`, which produces a fixed point only up to conversion (`Y f` and `f (Y f)` reduce to a
+/// common term), `U U f` reduces to `f (U U f)` in two steps, so a recursive function defined
+/// with it unfolds directly. Since `U U` is already a redex, it has the same limitations as `Θ`:
+/// it is suitable for `NOR` (normal), `HNO` (hybrid normal), `CBN` (call-by-name) and `HSP`
+/// (head spine) reduction `Order`s.
+///
+/// U ≡ λxy.y (x x y) ≡ λ λ 1 (2 2 1)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::combinators::{T, U};
+/// use lambda_calculus::data::num::church::{is_zero, mul, pred};
+/// use lambda_calculus::*;
+///
+/// // FAC ≡ λfn.IS_ZERO n ONE (MUL n (f (PRED n)))
+/// let fac = abs!(2, app!(
+///     is_zero(),
+///     Var(1),
+///     1.into_church(),
+///     app!(mul(), Var(1), app(Var(2), app(pred(), Var(1))))
+/// ));
+///
+/// assert_eq!(app(U(), U()), T());
+/// assert_eq!(beta(app!(U(), U(), fac, 3.into_church()), NOR, 0), 6.into_church());
+/// ```
+pub fn U() -> Term {
+    abs!(2, app(Var(1), app!(Var(2), Var(2), Var(1))))
+}
+
+/// An intermediate representation of a term during bracket abstraction.
+enum Ski {
+    S,
+    K,
+    I,
+    Var(usize),
+    App(Box<(Ski, Ski)>),
+}
+
+impl Ski {
+    fn from_term(term: &Term) -> Ski {
+        match term {
+            Var(i) => Ski::Var(*i),
+            Abs(ref body) => Ski::from_term(body).abstracted(),
+            App(boxed) => {
+                let (ref lhs, ref rhs) = **boxed;
+                Ski::App(Box::new((Ski::from_term(lhs), Ski::from_term(rhs))))
+            }
+        }
+    }
+
+    fn has_bound_variable(&self) -> bool {
+        match self {
+            Ski::Var(i) => *i == 1,
+            Ski::App(boxed) => boxed.0.has_bound_variable() || boxed.1.has_bound_variable(),
+            _ => false,
+        }
+    }
+
+    /// Removes the innermost binder from `self`, decrementing the indices of the other variables.
+    fn unbound(self) -> Ski {
+        match self {
+            Ski::Var(i) => Ski::Var(i.saturating_sub(1)), // UD remains undefined
+            Ski::App(boxed) => {
+                let (lhs, rhs) = *boxed;
+                Ski::App(Box::new((lhs.unbound(), rhs.unbound())))
+            }
+            other => other,
+        }
+    }
+
+    /// Performs bracket abstraction of the variable with the index `1`.
+    fn abstracted(self) -> Ski {
+        if !self.has_bound_variable() {
+            return Ski::App(Box::new((Ski::K, self.unbound())));
+        }
+
+        match self {
+            Ski::App(boxed) => {
+                let (lhs, rhs) = *boxed;
+                Ski::App(Box::new((
+                    Ski::App(Box::new((Ski::S, lhs.abstracted()))),
+                    rhs.abstracted(),
+                )))
+            }
+            _ => Ski::I, // the only remaining case is Var(1)
+        }
+    }
+
+    fn into_term(self) -> Term {
+        match self {
+            Ski::S => S(),
+            Ski::K => K(),
+            Ski::I => I(),
+            Ski::Var(i) => Var(i),
+            Ski::App(boxed) => {
+                let (lhs, rhs) = *boxed;
+                app(lhs.into_term(), rhs.into_term())
+            }
+        }
+    }
+}
+
+/// Translates a `Term` to the [SKI combinator calculus](https://en.wikipedia.org/wiki/SKI_combinator_calculus)
+/// using the classic bracket abstraction algorithm; the result is composed only of applications
+/// of the `S`, `K` and `I` combinators and the free variables of the `Term`, and it is
+/// β-equivalent to it.
+///
+/// The abstractions are eliminated from the innermost one with the following rules:
+///
+/// * `λx.x` becomes `I`
+/// * `λx.E` becomes `K E` if `x` is not free in `E`
+/// * `λx.E₁ E₂` becomes `S (λx.E₁) (λx.E₂)`
+///
+/// The result grows quickly with the nesting of abstractions; it could be made smaller by using
+/// the `B` and `C` combinators for applications in which only one side contains `x`, but then it
+/// wouldn't be limited to the SKI basis.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::combinators::{to_ski, I, K, S};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(to_ski(&I()), I());
+/// assert_eq!(to_ski(&K()), app!(S(), app(K(), K()), I()));
+/// assert_eq!(to_ski(&abs(app(Var(2), Var(1)))), app!(S(), app(K(), Var(1)), I()));
+/// ```
+pub fn to_ski(term: &Term) -> Term {
+    Ski::from_term(term).into_term()
+}
@@ -155,6 +155,7 @@ pub fn nand() -> Term {
 /// assert_eq!(beta(app!(if_else(), tru(), tru(), fls()), NOR, 0), tru());
 /// assert_eq!(beta(app!(if_else(), fls(), tru(), fls()), NOR, 0), fls());
 /// ```
+#[doc(alias = "if_then_else")]
 pub fn if_else() -> Term {
     abs!(3, app!(Var(3), Var(2), Var(1)))
 }
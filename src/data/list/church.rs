@@ -1,6 +1,13 @@
 //! [Church right fold list](https://ifl2014.github.io/submissions/ifl2014_submission_13.pdf)
+//!
+//! A Church-encoded list is its own right fold: the empty list is `λnc.n` and a list with the
+//! head `h` and the tail `t` is `λnc.c h (t n c)`, so `[1, 2]` is `λnc.c 1 (c 2 n)`. Lists can be
+//! built with `cons` and `nil` or converted from `Vec`s with `into_church()`.
 
+use crate::combinators::I;
 use crate::data::boolean::{fls, tru};
+use crate::data::num::church::{add, eq, is_zero, leq, mul, one, pred, sub, succ, zero};
+use crate::data::option::{none, some};
 use crate::data::pair::{fst, pair, snd};
 use crate::term::Term::*;
 use crate::term::{abs, app, Term, UD};
@@ -71,7 +78,7 @@ pub fn cons() -> Term {
 ///
 /// # Example
 /// ```
-/// use lambda_calculus::data::list::church::head;
+/// use lambda_calculus::data::list::church::{cons, head, nil};
 /// use lambda_calculus::*;
 ///
 /// let list = vec![1, 2, 3].into_church();
@@ -80,6 +87,10 @@ pub fn cons() -> Term {
 ///     beta(app(head(), list), NOR, 0),
 ///     1.into_church()
 /// );
+///
+/// let singleton = app!(cons(), 1.into_church(), nil());
+///
+/// assert_eq!(beta(app(head(), singleton), NOR, 0), 1.into_church());
 /// ```
 pub fn head() -> Term {
     abs(app!(Var(1), UD, abs!(2, Var(2))))
@@ -119,3 +130,655 @@ pub fn tail() -> Term {
         )
     ))
 }
+
+/// Applied to two Church-encoded lists it concatenates them. Since the first list is its own fold,
+/// it only needs to be applied to the second one in place of its `nil`, so the number of
+/// β-reductions (6 for lists in normal form) doesn't depend on the lengths of the lists.
+///
+/// APPEND ≡ λabnc.a (b n c) c ≡ λ λ λ λ 4 (3 2 1) 1
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::append;
+/// use lambda_calculus::*;
+///
+/// let list1 = vec![1, 2].into_church();
+/// let list2 = vec![3, 4].into_church();
+///
+/// assert_eq!(
+///     beta(app!(append(), list1, list2), NOR, 0),
+///     vec![1, 2, 3, 4].into_church()
+/// );
+/// ```
+pub fn append() -> Term {
+    abs!(4, app!(Var(4), app!(Var(3), Var(2), Var(1)), Var(1)))
+}
+
+/// Applied to a Church-encoded list it reverses it. The list is folded into a function that
+/// accumulates its elements in reverse order, so the number of β-reductions is linear in its
+/// length (`3n + 4` for a list of length `n` in normal form).
+///
+/// REVERSE ≡ λlnc.l I (λhka.k (c h a)) n ≡ λ λ λ 3 I (λ λ λ 2 (4 3 1)) 2
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::reverse;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(
+///     beta(app(reverse(), vec![1, 2, 3].into_church()), NOR, 0),
+///     vec![3, 2, 1].into_church()
+/// );
+/// ```
+pub fn reverse() -> Term {
+    abs!(
+        3,
+        app!(
+            Var(3),
+            I(),
+            abs!(3, app(Var(2), app!(Var(4), Var(3), Var(1)))),
+            Var(2)
+        )
+    )
+}
+
+/// Applied to a function and a Church-encoded list it maps the function over it.
+///
+/// MAP ≡ λflnc.l n (λht.c (f h) t) ≡ λ λ λ λ 3 2 (λ λ 3 (6 2) 1)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{map, nil};
+/// use lambda_calculus::data::num::church::succ;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(
+///     beta(app!(map(), succ(), vec![1, 2, 3].into_church()), NOR, 0),
+///     vec![2, 3, 4].into_church()
+/// );
+/// assert_eq!(beta(app!(map(), succ(), nil()), NOR, 0), nil());
+/// ```
+pub fn map() -> Term {
+    abs!(
+        4,
+        app!(
+            Var(3),
+            Var(2),
+            abs!(2, app!(Var(3), app(Var(6), Var(2)), Var(1)))
+        )
+    )
+}
+
+/// Applied to a predicate and a Church-encoded list it filters the list based on the predicate.
+///
+/// FILTER ≡ λplnc.l n (λht.p h (c h t) t) ≡ λ λ λ λ 3 2 (λ λ 6 2 (3 2 1) 1)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{filter, nil};
+/// use lambda_calculus::data::num::church::is_zero;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(
+///     beta(app!(filter(), is_zero(), vec![0, 1, 0].into_church()), NOR, 0),
+///     vec![0, 0].into_church()
+/// );
+/// assert_eq!(beta(app!(filter(), is_zero(), nil()), NOR, 0), nil());
+/// ```
+pub fn filter() -> Term {
+    abs!(
+        4,
+        app!(
+            Var(3),
+            Var(2),
+            abs!(
+                2,
+                app!(Var(6), Var(2), app!(Var(3), Var(2), Var(1)), Var(1))
+            )
+        )
+    )
+}
+
+/// Applied to a function, a starting value and a Church-encoded list it performs a
+/// [right fold](https://en.wikipedia.org/wiki/Fold_(higher-order_function)#Folds_on_lists)
+/// on the list. Since a Church-encoded list is its own right fold, it is only applied to the
+/// starting value and the function.
+///
+/// FOLDR ≡ λfzl.l z f ≡ λ λ λ 1 2 3
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::foldr;
+/// use lambda_calculus::data::num::church::{add, sub};
+/// use lambda_calculus::*;
+///
+/// let list = || vec![1, 2, 3].into_church();
+///
+/// assert_eq!(beta(app!(foldr(), add(), 0.into_church(), list()), NOR, 0), 6.into_church());
+/// assert_eq!(beta(app!(foldr(), sub(), 0.into_church(), list()), NOR, 0), 1.into_church());
+/// ```
+pub fn foldr() -> Term {
+    abs!(3, app!(Var(1), Var(2), Var(3)))
+}
+
+/// Applied to a function, a starting value and a Church-encoded list it performs a
+/// [left fold](https://en.wikipedia.org/wiki/Fold_(higher-order_function)#Folds_on_lists)
+/// on the list. The list is right-folded into a function that passes the accumulator from its
+/// first element to its last one.
+///
+/// FOLDL ≡ λfzl.l I (λhka.k (f a h)) z ≡ λ λ λ 1 I (λ λ λ 2 (6 1 3)) 2
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::foldl;
+/// use lambda_calculus::data::num::church::{add, sub};
+/// use lambda_calculus::*;
+///
+/// let list = || vec![1, 2, 3].into_church();
+///
+/// assert_eq!(beta(app!(foldl(), add(), 0.into_church(), list()), NOR, 0), 6.into_church());
+/// assert_eq!(beta(app!(foldl(), sub(), 6.into_church(), list()), NOR, 0), 0.into_church());
+/// ```
+pub fn foldl() -> Term {
+    abs!(
+        3,
+        app!(
+            Var(1),
+            I(),
+            abs!(3, app(Var(2), app!(Var(6), Var(1), Var(3)))),
+            Var(2)
+        )
+    )
+}
+
+/// Applied to a Church-encoded list it returns its Church-encoded length.
+///
+/// LENGTH ≡ λl.l ZERO (λht.SUCC t) ≡ λ 1 ZERO (λ λ SUCC 1)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{length, nil};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(length(), nil()), NOR, 0), 0.into_church());
+/// assert_eq!(beta(app(length(), vec![1, 2, 3].into_church()), NOR, 0), 3.into_church());
+/// ```
+pub fn length() -> Term {
+    abs(app!(Var(1), zero(), abs!(2, app(succ(), Var(1)))))
+}
+
+/// Applied to a Church-encoded list of Church-encoded numbers it returns their sum; the sum of an
+/// empty list is zero.
+///
+/// SUM ≡ λl.l ZERO ADD ≡ λ 1 ZERO ADD
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{nil, sum};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(sum(), nil()), NOR, 0), 0.into_church());
+/// assert_eq!(beta(app(sum(), vec![1, 2, 3].into_church()), NOR, 0), 6.into_church());
+/// ```
+pub fn sum() -> Term {
+    abs(app!(Var(1), zero(), add()))
+}
+
+/// Applied to a Church-encoded list of Church-encoded numbers it returns their product; the
+/// product of an empty list is one.
+///
+/// PRODUCT ≡ λl.l ONE MUL ≡ λ 1 ONE MUL
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{nil, product};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(product(), nil()), NOR, 0), 1.into_church());
+/// assert_eq!(beta(app(product(), vec![2, 3, 4].into_church()), NOR, 0), 24.into_church());
+/// ```
+pub fn product() -> Term {
+    abs(app!(Var(1), one(), mul()))
+}
+
+/// Applied to an equality function, a value and a Church-encoded list it returns a
+/// lambda-encoded boolean indicating whether the list contains an element equal to the value;
+/// the equality function is applied to the value and an element and must return a
+/// lambda-encoded boolean.
+///
+/// CONTAINS ≡ λexl.l FALSE (λht.e x h TRUE t) ≡ λ λ λ 1 FALSE (λ λ 5 4 2 TRUE 1)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{contains, nil};
+/// use lambda_calculus::data::num::church::eq;
+/// use lambda_calculus::*;
+///
+/// let list = || vec![1, 2, 3].into_church();
+///
+/// assert_eq!(beta(app!(contains(), eq(), 2.into_church(), list()), NOR, 0), true.into());
+/// assert_eq!(beta(app!(contains(), eq(), 5.into_church(), list()), NOR, 0), false.into());
+/// assert_eq!(beta(app!(contains(), eq(), 1.into_church(), nil()), NOR, 0), false.into());
+/// ```
+#[doc(alias = "elem")]
+pub fn contains() -> Term {
+    abs!(
+        3,
+        app!(
+            Var(1),
+            fls(),
+            abs!(2, app!(Var(5), Var(4), Var(2), tru(), Var(1)))
+        )
+    )
+}
+
+/// Applied to a Church-encoded number and a Church-encoded list of Church-encoded numbers it
+/// returns a lambda-encoded boolean indicating whether the list contains the number.
+///
+/// CONTAINS_NUM ≡ CONTAINS EQ
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{contains_num, nil};
+/// use lambda_calculus::*;
+///
+/// let list = || vec![1, 2, 3].into_church();
+///
+/// assert_eq!(beta(app!(contains_num(), 2.into_church(), list()), NOR, 0), true.into());
+/// assert_eq!(beta(app!(contains_num(), 5.into_church(), list()), NOR, 0), false.into());
+/// assert_eq!(beta(app!(contains_num(), 1.into_church(), nil()), NOR, 0), false.into());
+/// ```
+#[doc(alias = "elem")]
+pub fn contains_num() -> Term {
+    app(contains(), eq())
+}
+
+/// Applied to a predicate and a Church-encoded list it returns the first element of the list
+/// that satisfies the predicate wrapped in a lambda-encoded option, or `NONE` if there is no
+/// such element; the predicate must return a lambda-encoded boolean.
+///
+/// FIND ≡ λpl.l NONE (λht.p h (SOME h) t) ≡ λ λ 1 NONE (λ λ 4 2 (SOME 2) 1)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::find;
+/// use lambda_calculus::data::num::church::is_zero;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(
+///     beta(app!(find(), is_zero(), vec![1, 0, 2].into_church()), NOR, 0),
+///     Some(0).into_church()
+/// );
+/// assert_eq!(
+///     beta(app!(find(), is_zero(), vec![1, 2, 3].into_church()), NOR, 0),
+///     None::<usize>.into_church()
+/// );
+/// ```
+pub fn find() -> Term {
+    abs!(
+        2,
+        app!(
+            Var(1),
+            none(),
+            abs!(2, app!(Var(4), Var(2), app(some(), Var(2)), Var(1)))
+        )
+    )
+}
+
+/// Applied to a predicate and a Church-encoded list it returns a lambda-encoded boolean
+/// indicating whether all the elements of the list satisfy the predicate; it is true for an
+/// empty list. The predicate must return a lambda-encoded boolean; when reduced in the normal
+/// order, the rest of the list is not inspected after an element fails it.
+///
+/// ALL ≡ λpl.l TRUE (λht.p h t FALSE) ≡ λ λ 1 TRUE (λ λ 4 2 1 FALSE)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{all, nil};
+/// use lambda_calculus::data::num::church::is_zero;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app!(all(), is_zero(), vec![0, 0, 0].into_church()), NOR, 0), true.into());
+/// assert_eq!(beta(app!(all(), is_zero(), vec![0, 1, 0].into_church()), NOR, 0), false.into());
+/// assert_eq!(beta(app!(all(), is_zero(), nil()), NOR, 0), true.into());
+/// ```
+#[doc(alias = "every")]
+pub fn all() -> Term {
+    abs!(
+        2,
+        app!(Var(1), tru(), abs!(2, app!(Var(4), Var(2), Var(1), fls())))
+    )
+}
+
+/// Applied to a predicate and a Church-encoded list it returns a lambda-encoded boolean
+/// indicating whether any element of the list satisfies the predicate; it is false for an empty
+/// list. The predicate must return a lambda-encoded boolean; when reduced in the normal order,
+/// the rest of the list is not inspected after an element satisfies it.
+///
+/// ANY ≡ λpl.l FALSE (λht.p h TRUE t) ≡ λ λ 1 FALSE (λ λ 4 2 TRUE 1)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{any, nil};
+/// use lambda_calculus::data::num::church::is_zero;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app!(any(), is_zero(), vec![1, 0, 2].into_church()), NOR, 0), true.into());
+/// assert_eq!(beta(app!(any(), is_zero(), vec![1, 2, 3].into_church()), NOR, 0), false.into());
+/// assert_eq!(beta(app!(any(), is_zero(), nil()), NOR, 0), false.into());
+/// ```
+pub fn any() -> Term {
+    abs!(
+        2,
+        app!(Var(1), fls(), abs!(2, app!(Var(4), Var(2), tru(), Var(1))))
+    )
+}
+
+/// Applied to a Church-encoded number `n` and a Church-encoded list it returns its `n`-th element
+/// (counting from `0`) as a lambda-encoded option; the option is empty if the list is too short.
+///
+/// NTH ≡ λnl.l (λk.NONE) (λhtk.IS_ZERO k (SOME h) (t (PRED k))) n
+///     ≡ λ λ 1 (λ NONE) (λ λ λ IS_ZERO 1 (SOME 3) (2 (PRED 1))) 2
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::nth;
+/// use lambda_calculus::*;
+///
+/// let list = || vec![10, 20, 30].into_church();
+///
+/// assert_eq!(beta(app!(nth(), 1.into_church(), list()), NOR, 0), Some(20).into_church());
+/// assert_eq!(beta(app!(nth(), 3.into_church(), list()), NOR, 0), None::<usize>.into_church());
+/// ```
+pub fn nth() -> Term {
+    abs!(
+        2,
+        app!(
+            Var(1),
+            abs(none()),
+            abs!(
+                3,
+                app!(
+                    is_zero(),
+                    Var(1),
+                    app(some(), Var(3)),
+                    app(Var(2), app(pred(), Var(1)))
+                )
+            ),
+            Var(2)
+        )
+    )
+}
+
+/// Applied to a Church-encoded number `n` and a Church-encoded list it returns a new list with
+/// the first `n` elements of the supplied list (or all of them, if there are fewer).
+///
+/// TAKE ≡ λnl.l (λk.NIL) (λhtk.IS_ZERO k NIL (CONS h (t (PRED k)))) n
+///      ≡ λ λ 1 (λ NIL) (λ λ λ IS_ZERO 1 NIL (CONS 3 (2 (PRED 1)))) 2
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::take;
+/// use lambda_calculus::*;
+///
+/// let list = || vec![1, 2, 3].into_church();
+///
+/// assert_eq!(beta(app!(take(), 2.into_church(), list()), NOR, 0), vec![1, 2].into_church());
+/// assert_eq!(beta(app!(take(), 5.into_church(), list()), NOR, 0), list());
+/// ```
+pub fn take() -> Term {
+    abs!(
+        2,
+        app!(
+            Var(1),
+            abs(nil()),
+            abs!(
+                3,
+                app!(
+                    is_zero(),
+                    Var(1),
+                    nil(),
+                    app!(cons(), Var(3), app(Var(2), app(pred(), Var(1))))
+                )
+            ),
+            Var(2)
+        )
+    )
+}
+
+/// Applied to a Church-encoded number `n` and a Church-encoded list it returns a new list without
+/// the first `n` elements of the supplied list (or an empty one, if there are fewer).
+///
+/// DROP ≡ λnl.l (λk.NIL) (λhtk.IS_ZERO k (CONS h (t ZERO)) (t (PRED k))) n
+///      ≡ λ λ 1 (λ NIL) (λ λ λ IS_ZERO 1 (CONS 3 (2 ZERO)) (2 (PRED 1))) 2
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{drop, nil};
+/// use lambda_calculus::*;
+///
+/// let list = || vec![1, 2, 3].into_church();
+///
+/// assert_eq!(beta(app!(drop(), 1.into_church(), list()), NOR, 0), vec![2, 3].into_church());
+/// assert_eq!(beta(app!(drop(), 5.into_church(), list()), NOR, 0), nil());
+/// ```
+pub fn drop() -> Term {
+    abs!(
+        2,
+        app!(
+            Var(1),
+            abs(nil()),
+            abs!(
+                3,
+                app!(
+                    is_zero(),
+                    Var(1),
+                    app!(cons(), Var(3), app(Var(2), zero())),
+                    app(Var(2), app(pred(), Var(1)))
+                )
+            ),
+            Var(2)
+        )
+    )
+}
+
+/// Applied to two Church-encoded numbers `a` and `b` it returns a Church-encoded list of the
+/// numbers from `a` (inclusive) to `b` (exclusive); the list is empty if `a` is not smaller than
+/// `b`.
+///
+/// RANGE ≡ λabnc.SUB b a (λfk.c k (f (SUCC k))) (λk.n) a
+///       ≡ λ λ λ λ SUB 3 4 (λ λ 3 1 (2 (SUCC 1))) (λ 3) 4
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{nil, range};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(
+///     beta(app!(range(), 1.into_church(), 4.into_church()), NOR, 0),
+///     vec![1, 2, 3].into_church()
+/// );
+/// assert_eq!(beta(app!(range(), 2.into_church(), 2.into_church()), NOR, 0), nil());
+/// ```
+pub fn range() -> Term {
+    abs!(
+        4,
+        app!(
+            sub(),
+            Var(3),
+            Var(4),
+            abs!(2, app!(Var(3), Var(1), app(Var(2), app(succ(), Var(1))))),
+            abs(Var(3)),
+            Var(4)
+        )
+    )
+}
+
+/// Applied to a Church-encoded number `n` and an argument it produces a Church-encoded list
+/// containing the argument repeated `n` times.
+///
+/// REPLICATE ≡ λkxnc.k (c x) n ≡ λ λ λ λ 4 (1 3) 2
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{nil, replicate};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(
+///     beta(app!(replicate(), 3.into_church(), 0.into_church()), NOR, 0),
+///     vec![0, 0, 0].into_church()
+/// );
+/// assert_eq!(beta(app!(replicate(), 0.into_church(), 4.into_church()), NOR, 0), nil());
+/// ```
+#[doc(alias = "repeat")]
+pub fn replicate() -> Term {
+    abs!(4, app!(Var(4), app(Var(1), Var(3)), Var(2)))
+}
+
+/// Applied to a function and two Church-encoded lists it applies the function to the
+/// corresponding elements and returns the resulting list. If one input list is shorter, excess
+/// elements of the longer list are discarded.
+///
+/// The first list is folded into a function consuming the second one, which needs to be
+/// deconstructed with `HEAD` and `TAIL`; since `TAIL` is linear in the length of a Church-encoded
+/// list, the number of steps is quadratic.
+///
+/// ZIP_WITH ≡ λfab.a (λm.NIL) (λhtm.IS_NIL m (λx.NIL) (λx.CONS (f h (HEAD m)) (t (TAIL m))) I) b
+///          ≡ λ λ λ 2 (λ NIL) (λ λ λ IS_NIL 1 (λ NIL) (λ CONS (7 4 (HEAD 2)) (3 (TAIL 2))) I) 1
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::zip_with;
+/// use lambda_calculus::data::num::church::add;
+/// use lambda_calculus::*;
+///
+/// let list1 = vec![1, 2, 3].into_church();
+/// let list2 = vec![10, 20, 30].into_church();
+///
+/// assert_eq!(
+///     beta(app!(zip_with(), add(), list1, list2), NOR, 0),
+///     vec![11, 22, 33].into_church()
+/// );
+/// ```
+pub fn zip_with() -> Term {
+    abs!(
+        3,
+        app!(
+            Var(2),
+            abs(nil()),
+            abs!(
+                3,
+                app!(
+                    is_nil(),
+                    Var(1),
+                    abs(nil()),
+                    abs(app!(
+                        cons(),
+                        app!(Var(7), Var(4), app(head(), Var(2))),
+                        app(Var(3), app(tail(), Var(2)))
+                    )),
+                    I()
+                )
+            ),
+            Var(1)
+        )
+    )
+}
+
+/// Applied to two Church-encoded lists it returns a list of corresponding pairs. If one input list
+/// is shorter, excess elements of the longer list are discarded.
+///
+/// ZIP ≡ ZIP_WITH PAIR
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::zip;
+/// use lambda_calculus::*;
+///
+/// let list1 = vec![1, 2].into_church();
+/// let list2 = vec![3, 4].into_church();
+/// let pairs = vec![(1, 3).into_church(), (2, 4).into_church()].into_church();
+///
+/// assert_eq!(beta(app!(zip(), list1, list2), NOR, 0), pairs);
+/// ```
+pub fn zip() -> Term {
+    app(zip_with(), pair())
+}
+
+/// Applied to a Church-encoded number and a sorted Church-encoded list of Church-encoded numbers
+/// it inserts the number before the first element that is not lower than it, keeping the list
+/// sorted. The list is folded into a pair of the result and the original tail.
+///
+/// INSERT ≡ λxl.FST (l (PAIR (CONS x NIL) NIL) (λhp.LEQ x h (PAIR (CONS x (CONS h (SND p)))
+///                                                                (CONS h (SND p)))
+///                                                          (PAIR (CONS h (FST p))
+///                                                                (CONS h (SND p)))))
+///        ≡ λ λ FST (1 (PAIR (CONS 2 NIL) NIL) (λ λ LEQ 4 2 (PAIR (CONS 4 (CONS 2 (SND 1)))
+///                                                                (CONS 2 (SND 1)))
+///                                                          (PAIR (CONS 2 (FST 1))
+///                                                                (CONS 2 (SND 1)))))
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{insert, nil};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(
+///     beta(app!(insert(), 2.into_church(), vec![1, 3].into_church()), NOR, 0),
+///     vec![1, 2, 3].into_church()
+/// );
+/// assert_eq!(
+///     beta(app!(insert(), 2.into_church(), nil()), NOR, 0),
+///     vec![2].into_church()
+/// );
+/// ```
+pub fn insert() -> Term {
+    abs!(
+        2,
+        app(
+            fst(),
+            app!(
+                Var(1),
+                app!(pair(), app!(cons(), Var(2), nil()), nil()),
+                abs!(
+                    2,
+                    app!(
+                        leq(),
+                        Var(4),
+                        Var(2),
+                        app!(
+                            pair(),
+                            app!(cons(), Var(4), app!(cons(), Var(2), app(snd(), Var(1)))),
+                            app!(cons(), Var(2), app(snd(), Var(1)))
+                        ),
+                        app!(
+                            pair(),
+                            app!(cons(), Var(2), app(fst(), Var(1))),
+                            app!(cons(), Var(2), app(snd(), Var(1)))
+                        )
+                    )
+                )
+            )
+        )
+    )
+}
+
+/// Applied to a Church-encoded list of Church-encoded numbers it sorts it in ascending order
+/// using insertion sort; the number of reduction steps grows quadratically with the length of
+/// the list.
+///
+/// SORT ≡ λl.l NIL INSERT ≡ λ 1 NIL INSERT
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::church::{nil, sort};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(sort(), vec![3, 1, 2].into_church()), NOR, 1000), vec![1, 2, 3].into_church());
+/// assert_eq!(beta(app(sort(), vec![1, 2, 3].into_church()), NOR, 1000), vec![1, 2, 3].into_church());
+/// assert_eq!(beta(app(sort(), vec![3, 2, 1].into_church()), NOR, 1000), vec![1, 2, 3].into_church());
+/// assert_eq!(beta(app(sort(), nil()), NOR, 1000), nil());
+/// ```
+pub fn sort() -> Term {
+    abs(app!(Var(1), nil(), insert()))
+}
@@ -20,6 +20,23 @@ make_trait!(IntoChurchList, into_church);
 make_trait!(IntoScottList, into_scott);
 make_trait!(IntoParigotList, into_parigot);
 
+/// A conversion from a Church-encoded list in its normal form (e.g. obtained with
+/// `beta(term, NOR, 0)`) back to a `Vec` of Rust values.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::data::list::church::cons;
+///
+/// let list = beta(app!(cons(), 1.into_church(), vec![2, 3].into_church()), NOR, 0);
+///
+/// assert_eq!(Vec::<usize>::from_church(list), Ok(vec![1, 2, 3]));
+/// ```
+pub trait FromChurchList: Sized {
+    #[doc = "Performs the conversion."]
+    fn from_church(term: Term) -> Result<Self, ConversionError>;
+}
+
 impl IntoPairList for Vec<Term> {
     fn into_pair_list(self) -> Term {
         let mut ret = abs!(2, Var(1));
@@ -53,6 +70,65 @@ impl<T: IntoChurchNum> IntoChurchList for Vec<T> {
     }
 }
 
+impl<T: FromChurchNum> FromChurchList for Vec<T> {
+    fn from_church(term: Term) -> Result<Self, ConversionError> {
+        let mut inner = term
+            .unabs()
+            .and_then(|t| t.unabs())
+            .map_err(|_| ConversionError::InvalidTerm)?;
+        let mut elements = Vec::new();
+
+        loop {
+            match inner {
+                Var(2) => return Ok(elements),
+                App(boxed) => match *boxed {
+                    (App(cons), tail) => match *cons {
+                        (Var(1), head) => {
+                            elements.push(T::from_church(head)?);
+                            inner = tail;
+                        }
+                        _ => return Err(ConversionError::InvalidTerm),
+                    },
+                    _ => return Err(ConversionError::InvalidTerm),
+                },
+                _ => return Err(ConversionError::InvalidTerm),
+            }
+        }
+    }
+}
+
+/// Encodes a string as a Church list of the Church-encoded Unicode code points (`char`s) of its
+/// characters, not of its UTF-8 bytes; this way every element of the list is a whole character
+/// and e.g. the length of the list is the number of characters.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+///
+/// assert_eq!("AB".into_church(), vec![65, 66].into_church());
+/// assert_eq!("é".into_church(), vec![233].into_church()); // and not [195, 169]
+/// ```
+impl IntoChurchList for &str {
+    fn into_church(self) -> Term {
+        self.chars().collect::<Vec<char>>().into_church()
+    }
+}
+
+impl IntoChurchList for String {
+    fn into_church(self) -> Term {
+        self.as_str().into_church()
+    }
+}
+
+/// Decodes a Church list of Church-encoded Unicode code points, as produced by the
+/// `IntoChurchList` implementation for `&str`. Numbers that are not valid code points result
+/// in a `ConversionError::InvalidTerm`.
+impl FromChurchList for String {
+    fn from_church(term: Term) -> Result<Self, ConversionError> {
+        Vec::<char>::from_church(term).map(|chars| chars.into_iter().collect())
+    }
+}
+
 impl IntoScottList for Vec<Term> {
     fn into_scott(self) -> Term {
         let mut ret = abs!(2, Var(2));
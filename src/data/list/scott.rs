@@ -1,4 +1,11 @@
 //! [Scott list](https://ifl2014.github.io/submissions/ifl2014_submission_13.pdf)
+//!
+//! A Scott-encoded list is its own case analysis: `nil` selects the first of two arguments and
+//! `cons h t` applies the second one to `h` and `t`, so `l n (λht. c)` is `n` for an empty list
+//! and `c` (with `h` and `t` bound to its head and tail) otherwise. This eliminator is provided
+//! as `case` and `head`, `tail` and `is_nil` are its instances, which means that, unlike with
+//! Church lists (that have to be rebuilt by a fold to obtain the tail), deconstructing a list
+//! takes a constant number of steps.
 
 use crate::data::boolean::{fls, tru};
 use crate::term::Term::*;
@@ -13,7 +20,7 @@ pub fn nil() -> Term {
 
 /// Applied to a Scott-encoded list it determines if it is empty.
 ///
-/// IS_NIL ≡ λl.l TRUE (λax.FALSE) ≡ λ 1 TRUE (λ λ FALSE)
+/// IS_NIL ≡ λl.CASE l TRUE (λax.FALSE) ≡ λl.l TRUE (λax.FALSE) ≡ λ 1 TRUE (λ λ FALSE)
 ///
 /// # Example
 /// ```
@@ -61,9 +68,31 @@ pub fn cons() -> Term {
     abs!(4, app!(Var(1), Var(4), Var(3)))
 }
 
+/// Applied to a Scott-encoded list, a value `n` and a function `c` it pattern-matches on the
+/// list: it returns `n` if the list is empty and `c` applied to its head and tail otherwise.
+/// Since a Scott-encoded list is its own case analysis, this only makes the intent explicit.
+///
+/// CASE ≡ λlnc.l n c ≡ λ λ λ 3 2 1
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::list::scott::{case, nil};
+/// use lambda_calculus::*;
+///
+/// // λht.h returns the head of a non-empty list
+/// let first_or_zero = |list| app!(case(), list, 0.into_scott(), abs!(2, Var(2)));
+///
+/// assert_eq!(beta(first_or_zero(vec![1, 2].into_scott()), NOR, 0), 1.into_scott());
+/// assert_eq!(beta(first_or_zero(nil()), NOR, 0), 0.into_scott());
+/// ```
+#[doc(alias = "match")]
+pub fn case() -> Term {
+    abs!(3, app!(Var(3), Var(2), Var(1)))
+}
+
 /// Applied to a Scott-encoded list it returns its first element.
 ///
-/// HEAD ≡ λl.l UD (λht.h) ≡ λ 1 UD (λ λ 2)
+/// HEAD ≡ λl.CASE l UD (λht.h) ≡ λl.l UD (λht.h) ≡ λ 1 UD (λ λ 2)
 ///
 /// # Example
 /// ```
@@ -83,7 +112,7 @@ pub fn head() -> Term {
 
 /// Applied to a Scott-encoded list it returns a new list with all its elements but the first one.
 ///
-/// TAIL ≡ λl.l UD (λht.t) ≡ λ 1 UD (λ λ 1)
+/// TAIL ≡ λl.CASE l UD (λht.t) ≡ λl.l UD (λht.t) ≡ λ 1 UD (λ λ 1)
 ///
 /// # Example
 /// ```
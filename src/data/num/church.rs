@@ -1,12 +1,15 @@
 //! [Church numerals](https://en.wikipedia.org/wiki/Church_encoding#Church_numerals)
 
-use crate::combinators::{I, K, Z};
+use crate::combinators::{I, K, Y, Z};
 use crate::data::boolean::{and, fls, not, or, tru};
 use crate::data::num::{parigot, scott, stumpfu};
-use crate::data::pair::pair;
+use crate::data::pair::{fst, pair, snd};
+use crate::reduction::{beta, NOR};
 use crate::term::Term::*;
 use crate::term::{abs, app, Term};
 
+pub mod numerals;
+
 /// Produces a Church-encoded number zero; equivalent to `boolean::fls`.
 ///
 /// ZERO ≡ λfx.x ≡ λ λ 1 ≡ FALSE
@@ -70,15 +73,82 @@ pub fn succ() -> Term {
     abs!(3, app(Var(2), app!(Var(3), Var(2), Var(1))))
 }
 
-/// Applied to a Church-encoded number it produces its predecessor.
+/// Produces a Church-encoded number `n` by applying `SUCC` to `ZERO` `n` times and reducing the
+/// result to its normal form, which is the same as the `n.into_church()` literal; it illustrates
+/// that the numerals computed with `SUCC` and the ones built directly are indistinguishable once
+/// normalized.
+///
+/// FROM_USIZE_REDUCED n ≡ SUCC (SUCC (... (SUCC ZERO)))
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::from_usize_reduced;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(from_usize_reduced(0), 0.into_church());
+/// assert_eq!(from_usize_reduced(3), 3.into_church());
+/// ```
+pub fn from_usize_reduced(n: usize) -> Term {
+    let mut term = zero();
+
+    for _ in 0..n {
+        term = app(succ(), term);
+    }
+
+    beta(term, NOR, 0)
+}
+
+/// Applied to a Church-encoded number `n`, a function `f` and a value `x` it applies `f` to `x`
+/// `n` times; since a Church number is its own iterator, this only makes the intent explicit.
+///
+/// ITERATE ≡ λnfx.n f x ≡ λ λ λ 3 2 1
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::{iterate, succ};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app!(iterate(), 3.into_church(), succ(), 0.into_church()), NOR, 0), 3.into_church());
+/// assert_eq!(beta(app!(iterate(), 0.into_church(), succ(), 5.into_church()), NOR, 0), 5.into_church());
+/// ```
+pub fn iterate() -> Term {
+    abs!(3, app!(Var(3), Var(2), Var(1)))
+}
+
+/// Builds the application of the Church-encoded number `n` to the function `f` and the value
+/// `x`, i.e. `n f x`, which reduces to `f` applied to `x` `n` times.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::{apply, mul};
+/// use lambda_calculus::*;
+///
+/// let triple = app(mul(), 3.into_church());
+///
+/// assert_eq!(beta(apply(&2.into_church(), triple, 1.into_church()), NOR, 0), 9.into_church());
+/// ```
+#[doc(alias = "church_apply")]
+pub fn apply(n: &Term, f: Term, x: Term) -> Term {
+    app!(n.clone(), f, x)
+}
+
+/// Applied to a Church-encoded number it produces its predecessor; the predecessor of zero is
+/// zero.
 ///
 /// PRED ≡ λnfx.n (λgh.h (g f)) (λu.x) (λu.u) ≡ λ λ λ 3 (λ λ 1 (2 4)) (λ 2) (λ 1)
 ///
+/// A Church number can only be deconstructed by iterating over it, so its predecessor takes a
+/// number of steps linear in the number itself: `2n + 5` with the normal order for `n > 0`. The
+/// "wrapper" definition above is considerably cheaper than the one that iterates over pairs,
+/// `λn.FST (n (λp.PAIR (SND p) (SUCC (SND p))) (PAIR ZERO ZERO))`, which takes `10n + 6` steps;
+/// numerals with a constant-time predecessor are provided by the `scott` and `parigot` modules.
+///
 /// # Example
 /// ```
 /// use lambda_calculus::data::num::church::pred;
 /// use lambda_calculus::*;
 ///
+/// assert_eq!(beta(app(pred(), 0.into_church()), NOR, 0), 0.into_church());
 /// assert_eq!(beta(app(pred(), 1.into_church()), NOR, 0), 0.into_church());
 /// assert_eq!(beta(app(pred(), 3.into_church()), NOR, 0), 2.into_church());
 /// ```
@@ -110,7 +180,9 @@ pub fn add() -> Term {
     abs!(2, app!(Var(1), succ(), Var(2)))
 }
 
-/// Applied to two Church-encoded numbers it subtracts the second one from the first one.
+/// Applied to two Church-encoded numbers it subtracts the second one from the first one by
+/// applying `PRED` to the first one as many times as the second one indicates. The result
+/// saturates at zero, i.e. it is zero if the second number is greater than the first one.
 ///
 /// SUB ≡ λab.b PRED a ≡ λ λ 1 PRED 2
 ///
@@ -122,6 +194,7 @@ pub fn add() -> Term {
 /// assert_eq!(beta(app!(sub(), 1.into_church(), 0.into_church()), NOR, 0), 1.into_church());
 /// assert_eq!(beta(app!(sub(), 3.into_church(), 1.into_church()), NOR, 0), 2.into_church());
 /// assert_eq!(beta(app!(sub(), 5.into_church(), 2.into_church()), NOR, 0), 3.into_church());
+/// assert_eq!(beta(app!(sub(), 3.into_church(), 5.into_church()), NOR, 0), 0.into_church());
 /// ```
 pub fn sub() -> Term {
     abs!(2, app!(Var(1), pred(), Var(2)))
@@ -147,14 +220,21 @@ pub fn mul() -> Term {
 ///
 /// POW ≡ λab.IS_ZERO b ONE (b a) ≡ λ λ IS_ZERO 1 ONE (1 2)
 ///
+/// Any number raised to the power of zero is one, including zero itself (`0^0 = 1`); the
+/// `IS_ZERO` check is needed because `ZERO a` reduces to `λx.x` rather than to `ONE`.
+///
+/// The number of reduction steps grows with the result: normalizing `2^3` takes 22 steps using
+/// the normal order, but `2^10` already takes 2054.
+///
 /// # Example
 /// ```
 /// use lambda_calculus::data::num::church::pow;
 /// use lambda_calculus::*;
 ///
 /// assert_eq!(beta(app!(pow(), 3.into_church(), 0.into_church()), NOR, 0), 1.into_church());
+/// assert_eq!(beta(app!(pow(), 0.into_church(), 0.into_church()), NOR, 0), 1.into_church());
 /// assert_eq!(beta(app!(pow(), 2.into_church(), 1.into_church()), NOR, 0), 2.into_church());
-/// assert_eq!(beta(app!(pow(), 2.into_church(), 3.into_church()), NOR, 0), 8.into_church());
+/// assert_eq!(beta(app!(pow(), 2.into_church(), 3.into_church()), NOR, 100), 8.into_church());
 /// ```
 pub fn pow() -> Term {
     abs!(2, app!(is_zero(), Var(1), one(), app(Var(1), Var(2))))
@@ -346,10 +426,12 @@ pub fn div() -> Term {
 ///
 /// assert_eq!(beta(app!(quot(), 4.into_church(), 2.into_church()), NOR, 0), 2.into_church());
 /// assert_eq!(beta(app!(quot(), 5.into_church(), 3.into_church()), NOR, 0), 1.into_church());
+/// assert_eq!(beta(app!(quot(), 7.into_church(), 2.into_church()), NOR, 0), 3.into_church());
 /// ```
 /// # Errors
 ///
 /// This function will loop indefinitely if the second argument is `zero()`
+#[doc(alias = "divide")]
 pub fn quot() -> Term {
     app(
         Z(),
@@ -381,10 +463,12 @@ pub fn quot() -> Term {
 ///
 /// assert_eq!(beta(app!(rem(), 4.into_church(), 2.into_church()), NOR, 0), 0.into_church());
 /// assert_eq!(beta(app!(rem(), 5.into_church(), 3.into_church()), NOR, 0), 2.into_church());
+/// assert_eq!(beta(app!(rem(), 7.into_church(), 2.into_church()), NOR, 0), 1.into_church());
 /// ```
 /// # Errors
 ///
 /// This function will loop indefinitely if the second argument is `zero()`
+#[doc(alias = "modulo")]
 pub fn rem() -> Term {
     app(
         Z(),
@@ -402,18 +486,190 @@ pub fn rem() -> Term {
     )
 }
 
+/// Applied to two Church-encoded numbers it returns their Church-encoded greatest common
+/// divisor, computed with the Euclidean algorithm.
+///
+/// GCD ≡ Z (λgab.IS_ZERO b (λx.a) (λx.g b (REM a b)) I)
+///     ≡ Z (λ λ λ IS_ZERO 1 (λ 3) (λ 4 2 (REM 3 2)) I)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::gcd;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app!(gcd(), 12.into_church(), 18.into_church()), NOR, 0), 6.into_church());
+/// assert_eq!(beta(app!(gcd(), 5.into_church(), 0.into_church()), NOR, 0), 5.into_church());
+/// ```
+pub fn gcd() -> Term {
+    app(
+        Z(),
+        abs!(
+            3,
+            app!(
+                is_zero(),
+                Var(1),
+                abs(Var(3)),
+                abs(app!(Var(4), Var(2), app!(rem(), Var(3), Var(2)))),
+                I()
+            )
+        ),
+    )
+}
+
+/// Applied to two Church-encoded numbers it yields the Church-encoded result of the
+/// [Ackermann function](https://en.wikipedia.org/wiki/Ackermann_function) applied to them.
+///
+/// ACK ≡ Z (λfmn.IS_ZERO m (λx.SUCC n) (λx.IS_ZERO n (λy.f (PRED m) ONE)
+///                                                (λy.f (PRED m) (f m (PRED n))) I) I)
+///     ≡ Z (λ λ λ IS_ZERO 2 (λ SUCC 2) (λ IS_ZERO 2 (λ 5 (PRED 4) ONE)
+///                                                  (λ 5 (PRED 4) (5 4 (PRED 3))) I) I)
+///
+/// Since it is not primitive recursive, its result (and the number of reduction steps needed
+/// to obtain it) grows very quickly: using the normal order, `ACK 2 2` already takes 4257 steps.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::ackermann;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app!(ackermann(), 0.into_church(), 3.into_church()), NOR, 0), 4.into_church());
+/// assert_eq!(beta(app!(ackermann(), 1.into_church(), 2.into_church()), NOR, 0), 4.into_church());
+/// assert_eq!(
+///     beta(app!(ackermann(), 2.into_church(), 2.into_church()), NOR, 10000),
+///     7.into_church()
+/// );
+/// ```
+/// # Errors
+///
+/// This function may overflow the stack if its arguments are high enough.
+#[doc(alias = "ack")]
+pub fn ackermann() -> Term {
+    app(
+        Z(),
+        abs!(
+            3,
+            app!(
+                is_zero(),
+                Var(2),
+                abs(app(succ(), Var(2))),
+                abs(app!(
+                    is_zero(),
+                    Var(2),
+                    abs(app!(Var(5), app(pred(), Var(4)), one())),
+                    abs(app!(
+                        Var(5),
+                        app(pred(), Var(4)),
+                        app!(Var(5), Var(4), app(pred(), Var(3)))
+                    )),
+                    I()
+                )),
+                I()
+            )
+        ),
+    )
+}
+
+/// Applied to two Church-encoded numbers `b` and `n` it returns the Church-encoded number of
+/// digits of `n` in base `b`, i.e. how many times `n` can be divided by `b` before reaching zero.
+/// Zero has no digits. Division by one never reaches zero, so in base one (and in base zero, which
+/// has no digits at all) `n` itself is returned, i.e. its length in unary notation.
+///
+/// NUM_DIGITS ≡ λbn.IS_ZERO (PRED b) n (DIGITS b n) ≡ λ λ IS_ZERO (PRED 2) 1 (DIGITS 2 1)
+///
+/// DIGITS ≡ Z (λfbn.IS_ZERO n (λx.ZERO) (λx.SUCC (f b (QUOT_BY n b))) I)
+///        ≡ Z (λ λ λ IS_ZERO 1 (λ ZERO) (λ SUCC (4 3 (QUOT_BY 2 3))) I)
+///
+/// Instead of `QUOT`, which compares and subtracts whole numbers, the division uses the dividend
+/// to count down from the divisor repeatedly, so its cost only grows with their product:
+///
+/// QUOT_BY ≡ λnb.FST (n (λp.IS_ZERO (SND p) (PAIR (SUCC (FST p)) (PRED b))
+///                                          (PAIR (FST p) (PRED (SND p)))) (PAIR ZERO (PRED b)))
+///         ≡ λ λ FST (2 (λ IS_ZERO (SND 1) (PAIR (SUCC (FST 1)) (PRED 2))
+///                                         (PAIR (FST 1) (PRED (SND 1)))) (PAIR ZERO (PRED 1)))
+///
+/// The counters are not shared when reduced in the normal order, so an applicative order is
+/// much faster: using `HAP`, `NUM_DIGITS 10 100` takes 5760 reduction steps.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::num_digits;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app!(num_digits(), 10.into_church(), 100.into_church()), HAP, 0), 3.into_church());
+/// assert_eq!(beta(app!(num_digits(), 10.into_church(), 99.into_church()), HAP, 0), 2.into_church());
+/// assert_eq!(beta(app!(num_digits(), 2.into_church(), 8.into_church()), HAP, 0), 4.into_church());
+/// assert_eq!(beta(app!(num_digits(), 10.into_church(), 0.into_church()), HAP, 0), 0.into_church());
+/// assert_eq!(beta(app!(num_digits(), 1.into_church(), 5.into_church()), HAP, 0), 5.into_church());
+/// ```
+#[doc(alias = "log")]
+pub fn num_digits() -> Term {
+    abs!(
+        2,
+        app!(
+            is_zero(),
+            app(pred(), Var(2)),
+            Var(1),
+            app!(digits(), Var(2), Var(1))
+        )
+    )
+}
+
+// DIGITS from the documentation of `num_digits`
+fn digits() -> Term {
+    app(
+        Z(),
+        abs!(
+            3,
+            app!(
+                is_zero(),
+                Var(1),
+                abs(zero()),
+                abs(app(
+                    succ(),
+                    app!(Var(4), Var(3), app!(quot_by(), Var(2), Var(3)))
+                )),
+                I()
+            )
+        ),
+    )
+}
+
+// QUOT_BY from the documentation of `num_digits`
+fn quot_by() -> Term {
+    abs!(
+        2,
+        app(
+            fst(),
+            app!(
+                Var(2),
+                abs(app!(
+                    is_zero(),
+                    app(snd(), Var(1)),
+                    app!(pair(), app(succ(), app(fst(), Var(1))), app(pred(), Var(2))),
+                    app!(pair(), app(fst(), Var(1)), app(pred(), app(snd(), Var(1))))
+                )),
+                app!(pair(), zero(), app(pred(), Var(1)))
+            )
+        )
+    )
+}
+
 /// Applied to a Church-encoded number it yields its Church-encoded factorial.
 ///
 /// FAC ≡ λn. n (λfab. f (MUL a b) (SUCC b)) K ONE ONE
 ///     ≡ λ 1 (λ λ λ 3 (MUL 2 1) (SUCC 1)) K ONE ONE
 ///
+/// Its normalization is expensive, because the result is built in unary: using the normal order,
+/// `FAC 4` takes 118 reduction steps, `FAC 6` 2506 and `FAC 8` over 130000. A definition using general
+/// recursion with the `Y` combinator is provided by `fac_y`.
+///
 /// # Example
 /// ```
 /// use lambda_calculus::data::num::church::fac;
 /// use lambda_calculus::*;
 ///
 /// assert_eq!(beta(app(fac(), 3.into_church()), NOR, 0), 6.into_church());
-/// assert_eq!(beta(app(fac(), 4.into_church()), NOR, 0), 24.into_church());
+/// assert_eq!(beta(app(fac(), 4.into_church()), NOR, 200), 24.into_church());
 /// ```
 /// # Errors
 ///
@@ -431,7 +687,159 @@ pub fn fac() -> Term {
     ))
 }
 
-/// Applied to two Church-encoded numbers it returns the smaller one.
+/// Applied to a Church-encoded number it yields its Church-encoded factorial, using general
+/// recursion with the `Y` combinator instead of iterating over the number like `FAC`.
+///
+/// FAC_Y ≡ Y (λfn.IS_ZERO n ONE (MUL n (f (PRED n))))
+///       ≡ Y (λ λ IS_ZERO 1 ONE (MUL 1 (2 (PRED 1))))
+///
+/// The `Y` combinator has no normal form, so the term only normalizes with the normal order
+/// (and `HNO`); the applicative orders unfold it forever. It is also more expensive than `FAC`:
+/// `FAC_Y 4` takes 3873 reduction steps and `FAC_Y 6` over 200000.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::fac_y;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(fac_y(), 0.into_church()), NOR, 0), 1.into_church());
+/// assert_eq!(beta(app(fac_y(), 4.into_church()), NOR, 5000), 24.into_church());
+/// ```
+/// # Errors
+///
+/// This function will loop indefinitely if reduced with an applicative order.
+pub fn fac_y() -> Term {
+    app(
+        Y(),
+        abs!(
+            2,
+            app!(
+                is_zero(),
+                Var(1),
+                one(),
+                app!(mul(), Var(1), app(Var(2), app(pred(), Var(1))))
+            )
+        ),
+    )
+}
+
+/// Applied to a value `z`, a function `s` and a Church-encoded number `n` it performs primitive
+/// recursion: `REC z s 0 = z` and `REC z s (n + 1) = s n (REC z s n)`. Since a Church number can
+/// only iterate, it is used to build pairs of a counter and the result of the recursion so far,
+/// so no fixed-point combinator is needed.
+///
+/// REC ≡ λzsn.SND (n (λp.PAIR (SUCC (FST p)) (s (FST p) (SND p))) (PAIR ZERO z))
+///     ≡ λ λ λ SND (1 (λ PAIR (SUCC (FST 1)) (3 (FST 1) (SND 1))) (PAIR ZERO 3))
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::{mul, one, rec, succ};
+/// use lambda_calculus::*;
+///
+/// // λm r. MUL (SUCC m) r computes the factorial
+/// let fac = app!(rec(), one(), abs!(2, app!(mul(), app(succ(), Var(2)), Var(1))));
+///
+/// assert_eq!(beta(app(fac.clone(), 0.into_church()), NOR, 0), 1.into_church());
+/// assert_eq!(beta(app(fac, 4.into_church()), NOR, 0), 24.into_church());
+/// ```
+#[doc(alias = "primitive_recursion")]
+pub fn rec() -> Term {
+    abs!(
+        3,
+        app(
+            snd(),
+            app!(
+                Var(1),
+                abs(app!(
+                    pair(),
+                    app(succ(), app(fst(), Var(1))),
+                    app!(Var(3), app(fst(), Var(1)), app(snd(), Var(1)))
+                )),
+                app!(pair(), zero(), Var(3))
+            )
+        )
+    )
+}
+
+/// Applied to a Church-encoded number `n` it yields the Church-encoded `n`th Fibonacci number
+/// (counting from zero), computed by iterating over consecutive pairs of Fibonacci numbers.
+///
+/// FIB ≡ λn.FST (n (λp.PAIR (SND p) (ADD (FST p) (SND p))) (PAIR ZERO ONE))
+///     ≡ λ FST (1 (λ PAIR (SND 1) (ADD (FST 1) (SND 1))) (PAIR ZERO ONE))
+///
+/// The number of reduction steps grows roughly in proportion to the result: using the normal
+/// order, `FIB 7` takes 554 steps and `FIB 8` 930. The textbook doubly recursive
+/// definition using the `Y` combinator is provided by `fib_y`.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::fib;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(fib(), 0.into_church()), NOR, 0), 0.into_church());
+/// assert_eq!(beta(app(fib(), 1.into_church()), NOR, 0), 1.into_church());
+/// assert_eq!(beta(app(fib(), 7.into_church()), NOR, 1000), 13.into_church());
+/// ```
+pub fn fib() -> Term {
+    abs(app(
+        fst(),
+        app!(
+            Var(1),
+            abs(app!(
+                pair(),
+                app(snd(), Var(1)),
+                app!(add(), app(fst(), Var(1)), app(snd(), Var(1)))
+            )),
+            app!(pair(), zero(), one())
+        ),
+    ))
+}
+
+/// Applied to a Church-encoded number `n` it yields the Church-encoded `n`th Fibonacci number
+/// (counting from zero), using the textbook doubly recursive definition with the `Y` combinator
+/// instead of iterating over pairs like `FIB`.
+///
+/// FIB_Y ≡ Y (λfn.LEQ n ONE n (ADD (f (PRED n)) (f (PRED (PRED n)))))
+///       ≡ Y (λ λ LEQ 1 ONE 1 (ADD (2 (PRED 1)) (2 (PRED (PRED 1)))))
+///
+/// The `Y` combinator has no normal form, so the term only normalizes with the normal order
+/// (and `HNO`); the applicative orders unfold it forever. The recursive calls are not shared, so
+/// the cost grows exponentially: `FIB_Y 5` takes 1336 reduction steps and `FIB_Y 7` 5640.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::fib_y;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(fib_y(), 1.into_church()), NOR, 0), 1.into_church());
+/// assert_eq!(beta(app(fib_y(), 7.into_church()), NOR, 10000), 13.into_church());
+/// ```
+/// # Errors
+///
+/// This function will loop indefinitely if reduced with an applicative order.
+pub fn fib_y() -> Term {
+    app(
+        Y(),
+        abs!(
+            2,
+            app!(
+                leq(),
+                Var(1),
+                one(),
+                Var(1),
+                app!(
+                    add(),
+                    app(Var(2), app(pred(), Var(1))),
+                    app(Var(2), app(pred(), app(pred(), Var(1))))
+                )
+            )
+        ),
+    )
+}
+
+/// Applied to two Church-encoded numbers it returns the smaller one. Since lambda-encoded booleans
+/// select one of their arguments, the result of `LEQ` can be applied directly instead of being
+/// passed to `IF_ELSE`.
 ///
 /// MIN ≡ λaλb.(LEQ a b) a b ≡ λ λ (LEQ 2 1) 2 1
 ///
@@ -441,6 +849,8 @@ pub fn fac() -> Term {
 /// use lambda_calculus::*;
 ///
 /// assert_eq!(beta(app!(min(), 4.into_church(), 3.into_church()), NOR, 0), 3.into_church());
+/// assert_eq!(beta(app!(min(), 3.into_church(), 5.into_church()), NOR, 0), 3.into_church());
+/// assert_eq!(beta(app!(min(), 4.into_church(), 4.into_church()), NOR, 0), 4.into_church());
 /// ```
 pub fn min() -> Term {
     abs!(2, app!(app!(leq(), Var(2), Var(1)), Var(2), Var(1)))
@@ -456,6 +866,8 @@ pub fn min() -> Term {
 /// use lambda_calculus::*;
 ///
 /// assert_eq!(beta(app!(max(), 4.into_church(), 3.into_church()), NOR, 0), 4.into_church());
+/// assert_eq!(beta(app!(max(), 3.into_church(), 5.into_church()), NOR, 0), 5.into_church());
+/// assert_eq!(beta(app!(max(), 4.into_church(), 4.into_church()), NOR, 0), 4.into_church());
 /// ```
 pub fn max() -> Term {
     abs!(2, app!(app!(leq(), Var(2), Var(1)), Var(1), Var(2)))
@@ -510,7 +922,7 @@ pub fn shr() -> Term {
 }
 
 /// Applied to a Church-encoded number it produces a lambda-encoded boolean, indicating whether its
-/// argument is even.
+/// argument is even. The number is used to apply `NOT` to `TRUE` as many times as it indicates.
 ///
 /// IS_EVEN ≡ λx.x NOT TRUE ≡ λ 1 NOT TRUE
 ///
@@ -523,6 +935,10 @@ pub fn shr() -> Term {
 /// assert_eq!(beta(app(is_even(), 1.into_church()), NOR, 0), false.into());
 /// assert_eq!(beta(app(is_even(), 2.into_church()), NOR, 0), true.into());
 /// assert_eq!(beta(app(is_even(), 3.into_church()), NOR, 0), false.into());
+///
+/// // the result can be read back as a Rust `bool`
+/// assert_eq!(bool::from_church(beta(app(is_even(), 4.into_church()), NOR, 0)), Ok(true));
+/// assert_eq!(bool::from_church(beta(app(is_even(), 5.into_church()), NOR, 0)), Ok(false));
 /// ```
 pub fn is_even() -> Term {
     abs(app!(Var(1), not(), tru()))
@@ -547,7 +963,83 @@ pub fn is_odd() -> Term {
     abs(app!(Var(1), not(), fls()))
 }
 
-/// Applied to a Church-encoded number it produces the equivalent Scott-encoded number.
+/// Applied to a Church-encoded number it produces the Church-encoded number twice as big.
+///
+/// DOUBLE ≡ λn.ADD n n ≡ λ ADD 1 1
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::double;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(double(), 0.into_church()), NOR, 0), 0.into_church());
+/// assert_eq!(beta(app(double(), 3.into_church()), NOR, 0), 6.into_church());
+/// ```
+pub fn double() -> Term {
+    abs(app!(add(), Var(1), Var(1)))
+}
+
+/// Applied to a Church-encoded number it produces the Church-encoded half of it, rounded down.
+/// Instead of a recursive division, the number is used to iterate over pairs of a result and a
+/// boolean indicating whether the number counted so far is odd; the result is incremented at
+/// every odd step.
+///
+/// HALF ≡ λn.FST (n (λp.SND p (PAIR (SUCC (FST p)) FALSE) (PAIR (FST p) TRUE)) (PAIR ZERO FALSE))
+///      ≡ λ FST (1 (λ SND 1 (PAIR (SUCC (FST 1)) FALSE) (PAIR (FST 1) TRUE)) (PAIR ZERO FALSE))
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::half;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(half(), 0.into_church()), NOR, 0), 0.into_church());
+/// assert_eq!(beta(app(half(), 6.into_church()), NOR, 0), 3.into_church());
+/// assert_eq!(beta(app(half(), 7.into_church()), NOR, 0), 3.into_church());
+/// ```
+pub fn half() -> Term {
+    abs(app(
+        fst(),
+        app!(
+            Var(1),
+            abs(app!(
+                snd(),
+                Var(1),
+                app!(pair(), app(succ(), app(fst(), Var(1))), fls()),
+                app!(pair(), app(fst(), Var(1)), tru())
+            )),
+            app!(pair(), zero(), fls())
+        ),
+    ))
+}
+
+/// Applied to a Church-encoded number `n` it produces the next Church-encoded number of its
+/// [Collatz sequence](https://en.wikipedia.org/wiki/Collatz_conjecture): `n / 2` if `n` is even
+/// and `3n + 1` otherwise.
+///
+/// COLLATZ_STEP ≡ λn.IS_EVEN n (HALF n) (SUCC (MUL (SUCC (SUCC ONE)) n))
+///              ≡ λ IS_EVEN 1 (HALF 1) (SUCC (MUL (SUCC (SUCC ONE)) 1))
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::collatz_step;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(collatz_step(), 6.into_church()), NOR, 0), 3.into_church());
+/// assert_eq!(beta(app(collatz_step(), 5.into_church()), NOR, 0), 16.into_church());
+/// assert_eq!(beta(app(collatz_step(), 1.into_church()), NOR, 0), 4.into_church());
+/// ```
+pub fn collatz_step() -> Term {
+    abs(app!(
+        is_even(),
+        Var(1),
+        app(half(), Var(1)),
+        app(succ(), app!(mul(), app(succ(), app(succ(), one())), Var(1)))
+    ))
+}
+
+/// Applied to a Church-encoded number it produces the equivalent Scott-encoded number. The
+/// conversion is the inverse of `scott::to_church`; with the normal order it takes `n + 3`
+/// reduction steps.
 ///
 /// TO_SCOTT ≡ λn.n SUCC ZERO ≡ λ 1 SUCC ZERO
 ///
@@ -0,0 +1,25 @@
+//! Conversions of numeric literals to Church numerals
+
+use crate::data::num::convert::IntoChurchNum;
+use crate::term::Term;
+use std::num::ParseIntError;
+
+/// Parses a decimal number and produces the equivalent Church-encoded number; it is a shorthand
+/// for `s.parse::<usize>()` followed by `into_church()`.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::church::numerals::parse;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(parse("42"), Ok(42.into_church()));
+/// assert_eq!(parse("0"), Ok(0.into_church()));
+/// assert!(parse("abc").is_err());
+/// assert!(parse("-1").is_err());
+/// ```
+/// # Errors
+///
+/// Returns a `ParseIntError` if the string is not a valid `usize`.
+pub fn parse(s: &str) -> Result<Term, ParseIntError> {
+    s.parse::<usize>().map(IntoChurchNum::into_church)
+}
@@ -2,6 +2,7 @@
 
 #![allow(missing_docs)]
 
+use self::ConversionError::*;
 use self::Encoding::*;
 use crate::term::Term::*;
 use crate::term::{abs, app, Term};
@@ -31,6 +32,88 @@ make_trait!(IntoParigotNum, into_parigot);
 make_trait!(IntoStumpFuNum, into_stumpfu);
 make_trait!(IntoBinaryNum, into_binary);
 
+/// An error returned when a `Term` can't be converted to a Rust value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ConversionError {
+    /// the term is not in normal form or doesn't encode a value of the requested type
+    InvalidTerm,
+}
+
+/// A conversion from a lambda-encoded value in its normal form (e.g. obtained with
+/// `beta(term, NOR, 0)`) back to a Rust value. Numbers and the values contained in options and
+/// pairs are expected to be Church-encoded.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::data::num::church::add;
+///
+/// let sum = beta(app!(add(), 2.into_church(), 3.into_church()), NOR, 0);
+///
+/// assert_eq!(usize::from_church(sum), Ok(5));
+/// assert_eq!(usize::from_church(true.into()), Err(ConversionError::InvalidTerm));
+/// ```
+pub trait FromChurchNum: Sized {
+    #[doc = "Performs the conversion."]
+    fn from_church(term: Term) -> Result<Self, ConversionError>;
+}
+
+/// A conversion from a Scott-encoded number in its normal form back to a Rust value.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::data::num::scott::add;
+///
+/// let sum = beta(app!(add(), 2.into_scott(), 3.into_scott()), NOR, 0);
+///
+/// assert_eq!(usize::from_scott(sum), Ok(5));
+/// assert_eq!(usize::from_scott(2.into_church()), Err(ConversionError::InvalidTerm));
+/// ```
+pub trait FromScottNum: Sized {
+    #[doc = "Performs the conversion."]
+    fn from_scott(term: Term) -> Result<Self, ConversionError>;
+}
+
+/// A conversion from a Parigot-encoded number in its normal form back to a Rust value.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::data::num::parigot::add;
+///
+/// let sum = beta(app!(add(), 2.into_parigot(), 3.into_parigot()), NOR, 0);
+///
+/// assert_eq!(usize::from_parigot(sum), Ok(5));
+/// assert_eq!(usize::from_parigot(2.into_scott()), Err(ConversionError::InvalidTerm));
+/// ```
+pub trait FromParigotNum: Sized {
+    #[doc = "Performs the conversion."]
+    fn from_parigot(term: Term) -> Result<Self, ConversionError>;
+}
+
+/// A conversion from a signed number in its normal form, i.e. a pair of numbers `(p, n)`
+/// representing `p - n`, back to a Rust value. The pair doesn't need to be simplified.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::data::num::signed::add;
+///
+/// let sum = beta(app!(add(Church), 3.into_signed(Church), (-5).into_signed(Church)), NOR, 0);
+///
+/// assert_eq!(i64::from_signed(sum, Church), Ok(-2));
+/// assert_eq!(i64::from_signed((7, 2).into_scott(), Scott), Ok(5));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the `Encoding` is not `Church` or `Scott`.
+pub trait FromSignedNum: Sized {
+    #[doc = "Performs the conversion."]
+    fn from_signed(term: Term, encoding: Encoding) -> Result<Self, ConversionError>;
+}
+
 pub trait IntoSignedNum {
     #[doc = "Performs the conversion. The supported `Encoding`s are `Church`, `Scott`, `Parigot` and
           `StumpFu`."]
@@ -49,6 +132,87 @@ impl IntoChurchNum for usize {
     }
 }
 
+/// Decodes a Church numeral by counting the applications of its first variable, without
+/// performing any reductions; it therefore takes time linear in the size of the term and can't
+/// loop forever or overflow (the number can't exceed the number of nodes of the term). Terms that
+/// are not numerals in normal form, including unreduced ones like `SUCC 4`, result in an
+/// `InvalidTerm` error rather than a wrong number; to convert such terms, reduce them first with
+/// a limit on the number of steps, e.g. `usize::from_church(beta(term, NOR, 1000))`.
+impl FromChurchNum for usize {
+    fn from_church(term: Term) -> Result<Self, ConversionError> {
+        let mut inner = term
+            .unabs()
+            .and_then(|t| t.unabs())
+            .map_err(|_| InvalidTerm)?;
+        let mut n = 0;
+
+        while let App(boxed) = inner {
+            match *boxed {
+                (Var(2), rhs) => {
+                    inner = rhs;
+                    n += 1;
+                }
+                _ => return Err(InvalidTerm),
+            }
+        }
+
+        if inner == Var(1) {
+            Ok(n)
+        } else {
+            Err(InvalidTerm)
+        }
+    }
+}
+
+/// Encodes a `char` as the Church numeral of its Unicode code point.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+///
+/// assert_eq!('A'.into_church(), 65.into_church());
+/// assert_eq!(char::from_church(65.into_church()), Ok('A'));
+/// ```
+impl IntoChurchNum for char {
+    fn into_church(self) -> Term {
+        (self as usize).into_church()
+    }
+}
+
+/// Decodes a Church numeral as a `char`; numbers that are not Unicode scalar values (e.g.
+/// surrogates) result in an `InvalidTerm` error.
+impl FromChurchNum for char {
+    fn from_church(term: Term) -> Result<Self, ConversionError> {
+        u32::try_from(usize::from_church(term)?)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or(InvalidTerm)
+    }
+}
+
+impl FromChurchNum for bool {
+    fn from_church(term: Term) -> Result<Self, ConversionError> {
+        match term.unabs().and_then(|t| t.unabs()) {
+            Ok(Var(2)) => Ok(true),
+            Ok(Var(1)) => Ok(false),
+            _ => Err(InvalidTerm),
+        }
+    }
+}
+
+impl<T: FromChurchNum> FromChurchNum for Option<T> {
+    fn from_church(term: Term) -> Result<Self, ConversionError> {
+        match term.unabs().and_then(|t| t.unabs()) {
+            Ok(Var(2)) => Ok(None),
+            Ok(App(boxed)) => match *boxed {
+                (Var(1), value) => T::from_church(value).map(Some),
+                _ => Err(InvalidTerm),
+            },
+            _ => Err(InvalidTerm),
+        }
+    }
+}
+
 impl IntoScottNum for usize {
     fn into_scott(self) -> Term {
         let mut ret = abs!(2, Var(2));
@@ -61,6 +225,40 @@ impl IntoScottNum for usize {
     }
 }
 
+impl<A: FromChurchNum, B: FromChurchNum> FromChurchNum for (A, B) {
+    fn from_church(term: Term) -> Result<Self, ConversionError> {
+        let (lhs, b) = term
+            .unabs()
+            .and_then(|t| t.unapp())
+            .map_err(|_| InvalidTerm)?;
+
+        match lhs.unapp() {
+            Ok((Var(1), a)) => Ok((A::from_church(a)?, B::from_church(b)?)),
+            _ => Err(InvalidTerm),
+        }
+    }
+}
+
+impl FromScottNum for usize {
+    fn from_scott(mut term: Term) -> Result<Self, ConversionError> {
+        let mut n = 0;
+
+        loop {
+            match term.unabs().and_then(|t| t.unabs()) {
+                Ok(Var(2)) => return Ok(n),
+                Ok(App(boxed)) => match *boxed {
+                    (Var(1), pred) => {
+                        term = pred;
+                        n += 1;
+                    }
+                    _ => return Err(InvalidTerm),
+                },
+                _ => return Err(InvalidTerm),
+            }
+        }
+    }
+}
+
 impl IntoParigotNum for usize {
     fn into_parigot(self) -> Term {
         let mut ret = abs!(2, Var(1));
@@ -80,6 +278,31 @@ impl IntoParigotNum for usize {
     }
 }
 
+impl FromParigotNum for usize {
+    fn from_parigot(mut term: Term) -> Result<Self, ConversionError> {
+        let mut n = 0;
+
+        loop {
+            match term.unabs().and_then(|t| t.unabs()) {
+                Ok(Var(1)) => return Ok(n),
+                Ok(App(boxed)) => match *boxed {
+                    (App(inner), rest) => match *inner {
+                        (Var(2), pred)
+                            if pred.unabs_ref().and_then(|t| t.unabs_ref()) == Ok(&rest) =>
+                        {
+                            term = pred;
+                            n += 1;
+                        }
+                        _ => return Err(InvalidTerm),
+                    },
+                    _ => return Err(InvalidTerm),
+                },
+                _ => return Err(InvalidTerm),
+            }
+        }
+    }
+}
+
 impl IntoStumpFuNum for usize {
     fn into_stumpfu(self) -> Term {
         let mut ret = abs!(2, Var(1));
@@ -112,26 +335,60 @@ impl IntoBinaryNum for usize {
     }
 }
 
-impl IntoSignedNum for i32 {
-    fn into_signed(self, encoding: Encoding) -> Term {
-        let modulus = self.unsigned_abs() as usize;
+macro_rules! impl_signed {
+    ($type:ty) => {
+        impl IntoSignedNum for $type {
+            fn into_signed(self, encoding: Encoding) -> Term {
+                let modulus = self.unsigned_abs() as usize;
 
-        let numeral = match encoding {
-            Church => modulus.into_church(),
-            Scott => modulus.into_scott(),
-            Parigot => modulus.into_parigot(),
-            StumpFu => modulus.into_stumpfu(),
-            Binary => panic!("signed binary numbers are not supported"),
-        };
+                let numeral = |n: usize| match encoding {
+                    Church => n.into_church(),
+                    Scott => n.into_scott(),
+                    Parigot => n.into_parigot(),
+                    StumpFu => n.into_stumpfu(),
+                    Binary => panic!("signed binary numbers are not supported"),
+                };
 
-        if self > 0 {
-            tuple!(numeral, abs!(2, Var(1)))
-        } else {
-            tuple!(abs!(2, Var(1)), numeral)
+                if self > 0 {
+                    tuple!(numeral(modulus), numeral(0))
+                } else {
+                    tuple!(numeral(0), numeral(modulus))
+                }
+            }
         }
-    }
+
+        impl FromSignedNum for $type {
+            fn from_signed(term: Term, encoding: Encoding) -> Result<Self, ConversionError> {
+                let from_numeral = match encoding {
+                    Church => usize::from_church,
+                    Scott => usize::from_scott,
+                    _ => panic!("only Church and Scott signed numbers can be converted"),
+                };
+
+                let (lhs, negative) = term
+                    .unabs()
+                    .and_then(|t| t.unapp())
+                    .map_err(|_| InvalidTerm)?;
+                let positive = match lhs.unapp() {
+                    Ok((Var(1), positive)) => positive,
+                    _ => return Err(InvalidTerm),
+                };
+
+                let positive = <$type>::try_from(from_numeral(positive)?);
+                let negative = <$type>::try_from(from_numeral(negative)?);
+
+                match (positive, negative) {
+                    (Ok(p), Ok(n)) => Ok(p - n),
+                    _ => Err(InvalidTerm),
+                }
+            }
+        }
+    };
 }
 
+impl_signed!(i32);
+impl_signed!(i64);
+
 macro_rules! impl_pair {
     ($trait_name:ident, $function_name:ident) => {
         impl<T, U> $trait_name for (T, U)
@@ -1,4 +1,11 @@
 //! [Parigot numerals](https://ir.uiowa.edu/cgi/viewcontent.cgi?article=5357&context=etd)
+//!
+//! A Parigot-encoded number `n + 1` holds both its predecessor `n` (like a Scott number) and the
+//! result of the recursion over it (like a Church number), so it is its own recursor and its
+//! predecessor takes a constant number of reduction steps. The price is size: since the
+//! predecessor is contained twice, a Parigot number represented as a tree doubles in size with
+//! every successor (`3` has 45 nodes and `10` already 6141), which makes only small numbers
+//! practical.
 
 use crate::data::boolean::{fls, tru};
 use crate::term::Term::*;
@@ -83,6 +90,28 @@ pub fn pred() -> Term {
     abs(app!(Var(1), abs!(2, Var(2)), zero()))
 }
 
+/// Applied to a value `z`, a function `s` and a Parigot-encoded number `n` it performs primitive
+/// recursion: `REC z s 0 = z` and `REC z s (n + 1) = s n (REC z s n)`. Unlike with Scott numbers,
+/// no fixed-point combinator is needed, since a Parigot number already contains the results of
+/// the recursion.
+///
+/// REC ≡ λzsn.n s z ≡ λ λ λ 1 2 3
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::parigot::{rec, succ};
+/// use lambda_calculus::*;
+///
+/// // λm r. SUCC (SUCC r) doubles the number
+/// let double = app!(rec(), 0.into_parigot(), abs!(2, app(succ(), app(succ(), Var(1)))));
+///
+/// assert_eq!(beta(app(double.clone(), 0.into_parigot()), NOR, 0), 0.into_parigot());
+/// assert_eq!(beta(app(double, 3.into_parigot()), NOR, 0), 6.into_parigot());
+/// ```
+pub fn rec() -> Term {
+    abs!(3, app!(Var(1), Var(2), Var(3)))
+}
+
 /// Applied to two Parigot-encoded numbers it produces their sum.
 ///
 /// ADD ≡ λnm.n (λp.SUCC) m ≡ λ λ 2 (λ SUCC) 1
@@ -85,6 +85,45 @@ pub fn pred() -> Term {
     abs(app!(Var(1), zero(), abs(Var(1))))
 }
 
+/// Applied to a value `z`, a function `s` and a Scott-encoded number `n` it performs primitive
+/// recursion on `n`: it produces `z` if `n` is zero and `s m (REC z s m)` if `n` is the successor
+/// of `m`.
+///
+/// Unlike Church numerals, Scott numerals can't iterate by themselves; a Scott-encoded number is
+/// only its own case analysis (`n z s` produces `z` for zero and `s m` for the successor of `m`),
+/// which is what makes `PRED` constant-time, and this combinator provides the recursion.
+///
+/// REC ≡ Z (λfzsn.n z (λm.s m (f z s m))) ≡ Z (λ λ λ λ 1 3 (λ 3 1 (5 4 3 1)))
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::scott::{rec, succ};
+/// use lambda_calculus::*;
+///
+/// // λm r. SUCC (SUCC r) doubles the number
+/// let double = app!(rec(), 0.into_scott(), abs!(2, app(succ(), app(succ(), Var(1)))));
+///
+/// assert_eq!(beta(app(double.clone(), 0.into_scott()), NOR, 0), 0.into_scott());
+/// assert_eq!(beta(app(double, 3.into_scott()), NOR, 0), 6.into_scott());
+/// ```
+/// # Errors
+///
+/// This function will overflow the stack if used with an applicative-family (`APP` or `HAP`)
+/// reduction order.
+pub fn rec() -> Term {
+    app(
+        Z(),
+        abs!(
+            4,
+            app!(
+                Var(1),
+                Var(3),
+                abs(app!(Var(3), Var(1), app!(Var(5), Var(4), Var(3), Var(1))))
+            )
+        ),
+    )
+}
+
 /// Applied to two Scott-encoded numbers it produces their sum.
 ///
 /// ADD ≡ Z (λfmn.m n (λo. SUCC (f o n))) ≡ Z (λ λ λ 2 1 (λ SUCC (4 1 2)))
@@ -192,7 +231,10 @@ pub fn pow() -> Term {
     )
 }
 
-/// Applied to a Scott-encoded number it produces the equivalent Church-encoded number.
+/// Applied to a Scott-encoded number it produces the equivalent Church-encoded number. The
+/// conversion is the inverse of `church::to_scott`; since Scott numbers are not their own
+/// iterators it needs a fixed-point combinator, and with the normal order it takes `9 * (n + 1)`
+/// reduction steps.
 ///
 /// TO_CHURCH ≡ λabc.Z (λdefg.g f (λh.e (d e f h))) b c a
 ///           ≡ λ λ λ Z (λ λ λ λ 1 2 (λ 4 (5 4 3 1))) 2 1 3
@@ -142,6 +142,32 @@ pub fn modulus(encoding: Encoding) -> Term {
     ))
 }
 
+/// Applied to a signed integer with a specified encoding it returns a lambda-encoded boolean
+/// indicating whether it is equal to zero; the integer doesn't need to be simplified.
+///
+/// IS_ZERO ≡ λx.IS_ZERO (MODULUS x) ≡ λ IS_ZERO (MODULUS 1)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::num::signed::is_zero;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(is_zero(Church), 0.into_signed(Church)), NOR, 0), true.into());
+/// assert_eq!(beta(app(is_zero(Church), (2, 2).into_church()), NOR, 0), true.into());
+/// assert_eq!(beta(app(is_zero(Church), (-1).into_signed(Church)), NOR, 0), false.into());
+/// ```
+pub fn is_zero(encoding: Encoding) -> Term {
+    let is_zero = match encoding {
+        Church => church::is_zero(),
+        Scott => scott::is_zero(),
+        Parigot => parigot::is_zero(),
+        StumpFu => stumpfu::is_zero(),
+        Binary => panic!("signed binary numbers are not supported"),
+    };
+
+    abs(app(is_zero, app(modulus(encoding), Var(1))))
+}
+
 /// Applied to two signed integers with a specified encoding it returns a signed integer equal to
 /// their sum.
 ///
@@ -142,11 +142,89 @@ pub fn unwrap_or() -> Term {
 ///     app!(and_then(), Some(1).into_church(), some_succ.clone()), NOR, 0),
 ///     Some(2).into_church()
 /// );
+///
+/// // Equivalent to the closure `|_| { None }` in Rust; it short-circuits the rest of a chain
+/// let to_none: Term = abs(none());
+///
+/// assert_eq!(beta(
+///     app!(and_then(), app!(and_then(), Some(1).into_church(), some_succ.clone()), some_succ.clone()),
+///     NOR, 0),
+///     Some(3).into_church()
+/// );
+/// assert_eq!(beta(
+///     app!(and_then(), app!(and_then(), Some(1).into_church(), to_none), some_succ), NOR, 0),
+///     none()
+/// );
 /// ```
 pub fn and_then() -> Term {
     abs!(2, app!(Var(2), none(), Var(1)))
 }
 
+/// Applied to a lambda-encoded option containing another lambda-encoded option it returns the
+/// inner option, or the empty option if the outer one doesn't contain a value.
+///
+/// FLATTEN ≡ λm.m NONE I ≡ λ 1 NONE I
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::option::{flatten, none};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app(flatten(), Some(Some(1)).into_church()), NOR, 0), Some(1).into_church());
+/// assert_eq!(beta(app(flatten(), Some(None::<usize>).into_church()), NOR, 0), none());
+/// assert_eq!(beta(app(flatten(), none()), NOR, 0), none());
+/// ```
+pub fn flatten() -> Term {
+    abs(app!(Var(1), none(), I()))
+}
+
+/// Applied to a predicate and a lambda-encoded option it returns the option if it contains a value
+/// for which the predicate returns a lambda-encoded `true`, and the empty option otherwise.
+///
+/// FILTER ≡ λpm.m NONE (λx.p x (SOME x) NONE) ≡ λ λ 1 NONE (λ 3 1 (SOME 1) NONE)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::option::{filter, none};
+/// use lambda_calculus::data::num::church::is_zero;
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app!(filter(), is_zero(), Some(0).into_church()), NOR, 0), Some(0).into_church());
+/// assert_eq!(beta(app!(filter(), is_zero(), Some(1).into_church()), NOR, 0), none());
+/// assert_eq!(beta(app!(filter(), is_zero(), none()), NOR, 0), none());
+/// ```
+pub fn filter() -> Term {
+    abs!(
+        2,
+        app!(
+            Var(1),
+            none(),
+            abs(app!(Var(3), Var(1), app(some(), Var(1)), none()))
+        )
+    )
+}
+
+/// Applied to two lambda-encoded options it returns the first one if it contains a value and the
+/// second one otherwise.
+///
+/// OR ≡ λab.a b (λx.a) ≡ λ λ 2 1 (λ 3)
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::option::{or, none};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(beta(app!(or(), none(), Some(3).into_church()), NOR, 0), Some(3).into_church());
+/// assert_eq!(
+///     beta(app!(or(), Some(1).into_church(), Some(3).into_church()), NOR, 0),
+///     Some(1).into_church()
+/// );
+/// assert_eq!(beta(app!(or(), none(), none()), NOR, 0), none());
+/// ```
+pub fn or() -> Term {
+    abs!(2, app!(Var(2), Var(1), abs(Var(3))))
+}
+
 impl From<Option<Term>> for Term {
     fn from(option: Option<Term>) -> Term {
         match option {
@@ -159,6 +159,7 @@ pub fn unwrap_or() -> Term {
 /// assert_eq!(beta(app!(map(), succ(), ok_one.into_church()), NOR, 0), ok_two.into_church());
 /// assert_eq!(beta(app!(map(), succ(), err_two.into_church()), NOR, 0), err_two.into_church());
 /// ```
+#[doc(alias = "map_ok")]
 pub fn map() -> Term {
     abs!(2, app!(Var(1), abs(app(ok(), app(Var(3), Var(1)))), err()))
 }
@@ -217,6 +218,30 @@ pub fn and_then() -> Term {
     abs!(2, app!(Var(2), Var(1), err()))
 }
 
+/// Applied to two functions and a lambda-encoded `Result` it eliminates the `Result`, applying the
+/// second function to its contents if it is `Ok` or the first one if it is `Err`; since a
+/// lambda-encoded `Result` selects one of its two branches itself, it only needs to be applied to
+/// them in the reverse order.
+///
+/// MAP_OR_ELSE ≡ λdfm.m f d ≡ λ λ λ 1 2 3
+///
+/// # Example
+/// ```
+/// use lambda_calculus::data::result::map_or_else;
+/// use lambda_calculus::data::num::church::{pred, succ};
+/// use lambda_calculus::*;
+///
+/// let ok_one: Result<usize, usize> = Ok(1);
+/// let err_two: Result<usize, usize> = Err(2);
+///
+/// assert_eq!(beta(app!(map_or_else(), pred(), succ(), ok_one.into_church()), NOR, 0), 2.into_church());
+/// assert_eq!(beta(app!(map_or_else(), pred(), succ(), err_two.into_church()), NOR, 0), 1.into_church());
+/// ```
+#[doc(alias = "either")]
+pub fn map_or_else() -> Term {
+    abs!(3, app!(Var(1), Var(2), Var(3)))
+}
+
 impl From<Result<Term, Term>> for Term {
     fn from(result: Result<Term, Term>) -> Term {
         match result {
@@ -14,7 +14,7 @@ pub use self::reduction::beta;
 pub use self::reduction::Order::*;
 pub use self::term::Notation::*;
 pub use self::term::Term::*;
-pub use self::term::{abs, app, Term, UD};
+pub use self::term::{abs, abs_n, app, Term, UD};
 
 #[cfg(feature = "encoding")]
 pub mod data;
@@ -4,23 +4,85 @@ use self::CToken::*;
 use self::Expression::*;
 use self::ParseError::*;
 use self::Token::*;
+use crate::combinators::{I, K, S};
 pub use crate::term::Notation::*;
 use crate::term::Term::*;
 use crate::term::{abs, app, Notation, Term};
+use std::error;
+use std::fmt;
+use std::iter::{self, Peekable};
+use std::ops::Range;
 
 /// An error returned by `parse()` when a parsing issue is encountered.
 #[derive(Debug, PartialEq, Eq)]
 pub enum ParseError {
-    /// lexical error; contains the invalid character and its index
-    InvalidCharacter((usize, char)),
+    /// lexical error; contains the invalid character and its (one-based) line and column
+    InvalidCharacter {
+        /// the line containing the invalid character
+        line: usize,
+        /// the column of the invalid character, counted in `char`s
+        column: usize,
+        /// the invalid character
+        character: char,
+    },
     /// syntax error; the expression is invalid
     InvalidExpression,
     /// syntax error; the expression is empty
     EmptyExpression,
+    /// syntax error; a parenthesis is not matched by one of the opposite kind
+    UnbalancedParens,
+    /// syntax error; contains an identifier that is not bound by any abstraction
+    FreeVariable(String),
+    /// lexical error; a De Bruijn index doesn't fit in a `usize`; contains the position of its
+    /// first digit
+    IndexOverflow {
+        /// the line containing the index
+        line: usize,
+        /// the column of the first digit of the index, counted in `char`s
+        column: usize,
+    },
+    /// lexical error; a block comment is not closed; contains the position of its opening
+    UnterminatedComment {
+        /// the line of the opening of the comment
+        line: usize,
+        /// the column of the opening of the comment, counted in `char`s
+        column: usize,
+    },
 }
 
-#[derive(Debug, PartialEq, Eq)]
-#[doc(hidden)]
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InvalidCharacter {
+                line,
+                column,
+                character,
+            } => write!(
+                f,
+                "invalid character '{}' at line {} column {}",
+                character, line, column
+            ),
+            InvalidExpression => write!(f, "invalid expression"),
+            EmptyExpression => write!(f, "empty expression"),
+            UnbalancedParens => write!(f, "unbalanced parentheses"),
+            FreeVariable(name) => write!(f, "free variable '{}'", name),
+            IndexOverflow { line, column } => {
+                write!(f, "index too large at line {} column {}", line, column)
+            }
+            UnterminatedComment { line, column } => write!(
+                f,
+                "unterminated comment opened at line {} column {}",
+                line, column
+            ),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A token of the `DeBruijn` notation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
 pub enum Token {
     /// the lambda symbol ('λ' or '\')
     Lambda,
@@ -28,7 +90,7 @@ pub enum Token {
     Lparen,
     /// right parenthesis
     Rparen,
-    /// a hex-encoded digit
+    /// a De Bruijn index
     Number(usize),
 }
 
@@ -45,88 +107,310 @@ pub enum CToken {
     CName(String),
 }
 
-#[doc(hidden)]
-pub fn tokenize_dbr(input: &str) -> Result<Vec<Token>, ParseError> {
-    let chars = input.chars().enumerate();
-    let mut tokens = Vec::with_capacity(input.len());
-
-    for (i, c) in chars {
-        match c {
-            '\\' | 'λ' => tokens.push(Lambda),
-            '(' => tokens.push(Lparen),
-            ')' => tokens.push(Rparen),
-            _ => {
-                if let Some(n) = c.to_digit(16) {
-                    tokens.push(Number(n as usize))
-                } else if c.is_whitespace() {
-                    // ignore
-                } else {
-                    return Err(InvalidCharacter((i, c)));
+/// A character of the input along with its byte offset and its (one-based) line and column.
+type PositionedChar = (usize, usize, usize, char);
+
+/// Pairs the characters of the input with their byte offsets and (one-based) line and column
+/// numbers.
+fn positioned_chars(input: &str) -> impl Iterator<Item = PositionedChar> + '_ {
+    input
+        .char_indices()
+        .scan((1, 0), |(line, column), (offset, c)| {
+            *column += 1;
+            let ret = (offset, *line, *column, c);
+            if c == '\n' {
+                *line += 1;
+                *column = 0;
+            }
+            Some(ret)
+        })
+}
+
+fn invalid_character(line: usize, column: usize, character: char) -> ParseError {
+    InvalidCharacter {
+        line,
+        column,
+        character,
+    }
+}
+
+enum CommentState {
+    Code,
+    LineComment,
+    BlockCommentOpening {
+        line: usize,
+        column: usize,
+    },
+    BlockComment {
+        line: usize,
+        column: usize,
+        dash: bool,
+    },
+}
+
+/// Replaces the characters belonging to line (`--`) and block (`{- -}`) comments with spaces,
+/// preserving line breaks and the positions of all the characters; an unterminated block comment
+/// results in an error after the last character.
+fn skip_comments<I: Iterator<Item = PositionedChar>>(
+    chars: I,
+) -> impl Iterator<Item = Result<PositionedChar, ParseError>> {
+    use self::CommentState::*;
+
+    let mut chars = chars.peekable();
+    let mut state = Code;
+
+    iter::from_fn(move || {
+        let Some((offset, line, column, c)) = chars.next() else {
+            return match std::mem::replace(&mut state, Code) {
+                BlockCommentOpening { line, column } | BlockComment { line, column, .. } => {
+                    Some(Err(UnterminatedComment { line, column }))
+                }
+                _ => None,
+            };
+        };
+        let next = chars.peek().map(|&(.., c)| c);
+
+        let in_comment = match state {
+            Code => match (c, next) {
+                ('-', Some('-')) => {
+                    state = LineComment;
+                    true
                 }
+                ('{', Some('-')) => {
+                    state = BlockCommentOpening { line, column };
+                    true
+                }
+                _ => false,
+            },
+            LineComment => {
+                if c == '\n' {
+                    state = Code;
+                }
+                true
+            }
+            BlockCommentOpening { line, column } => {
+                state = BlockComment {
+                    line,
+                    column,
+                    dash: false,
+                };
+                true
             }
+            BlockComment { line, column, dash } => {
+                state = if dash && c == '}' {
+                    Code
+                } else {
+                    BlockComment {
+                        line,
+                        column,
+                        dash: c == '-',
+                    }
+                };
+                true
+            }
+        };
+
+        let c = if in_comment && c != '\n' { ' ' } else { c };
+
+        Some(Ok((offset, line, column, c)))
+    })
+}
+
+/// Writes `input` to `output` with its comments blanked out; if an unterminated comment is
+/// encountered, `output` contains everything preceding the error.
+fn blank_comments(input: &str, output: &mut String) -> Result<(), ParseError> {
+    for positioned in skip_comments(positioned_chars(input)) {
+        let (.., c) = positioned?;
+        output.push(c);
+    }
+
+    Ok(())
+}
+
+/// Returns an iterator over the `Token`s of the given input in `DeBruijn` notation, along with
+/// their byte offsets in the input. Whitespaces and comments are skipped. The iteration ends after
+/// the first error is encountered.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::parser::{tokens, Token::*};
+///
+/// let tokens: Vec<_> = tokens("λ1 {- x -} (λ12)").collect();
+///
+/// assert_eq!(
+///     tokens,
+///     vec![
+///         Ok((0, Lambda)),
+///         Ok((2, Number(1))),
+///         Ok((12, Lparen)),
+///         Ok((13, Lambda)),
+///         Ok((15, Number(12))),
+///         Ok((17, Rparen))
+///     ]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Yields a `ParseError` if an invalid character, an index that is too large or an unterminated
+/// comment is encountered.
+pub fn tokens(input: &str) -> impl Iterator<Item = Result<(usize, Token), ParseError>> + '_ {
+    spanned_tokens(input).map(|token| token.map(|(span, token)| (span.start, token)))
+}
+
+/// Like `tokens()`, but yields the byte ranges spanned by the `Token`s.
+fn spanned_tokens(
+    input: &str,
+) -> impl Iterator<Item = Result<(Range<usize>, Token), ParseError>> + '_ {
+    let mut chars = skip_comments(positioned_chars(input)).peekable();
+    let mut failed = false;
+
+    iter::from_fn(move || {
+        if failed {
+            return None;
         }
+        let token = next_token(&mut chars);
+        failed = matches!(token, Some(Err(_)));
+        token
+    })
+}
+
+fn next_token<I: Iterator<Item = Result<PositionedChar, ParseError>>>(
+    chars: &mut Peekable<I>,
+) -> Option<Result<(Range<usize>, Token), ParseError>> {
+    loop {
+        let (offset, line, column, c) = match chars.next()? {
+            Ok(positioned) => positioned,
+            Err(error) => return Some(Err(error)),
+        };
+        let mut end = offset + c.len_utf8();
+
+        let token = match c {
+            '\\' | 'λ' => Lambda,
+            '(' => Lparen,
+            ')' => Rparen,
+            _ if c.is_whitespace() => continue,
+            _ => {
+                let Some(digit) = c.to_digit(10) else {
+                    return Some(Err(invalid_character(line, column, c)));
+                };
+                let mut n = digit as usize;
+                while let Some(&Ok((_, _, _, c))) = chars.peek() {
+                    let Some(digit) = c.to_digit(10) else {
+                        break;
+                    };
+                    match n
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(digit as usize))
+                    {
+                        Some(next) => n = next,
+                        None => return Some(Err(IndexOverflow { line, column })),
+                    }
+                    chars.next();
+                    end += 1; // the digits are ASCII
+                }
+                Number(n)
+            }
+        };
+
+        return Some(Ok((offset..end, token)));
     }
+}
 
-    Ok(tokens)
+#[doc(hidden)]
+pub fn tokenize_dbr(input: &str) -> Result<Vec<Token>, ParseError> {
+    tokens(input)
+        .map(|token| token.map(|(_, token)| token))
+        .collect()
 }
 
 #[doc(hidden)]
 pub fn tokenize_cla(input: &str) -> Result<Vec<CToken>, ParseError> {
-    let mut chars = input.chars().enumerate().peekable();
-    let mut tokens = Vec::with_capacity(input.len());
+    let mut tokens = Vec::new();
+    spanned_classic_tokens(input, &mut tokens)?;
+
+    Ok(tokens.into_iter().map(|(_, token)| token).collect())
+}
+
+/// Pushes the `CToken`s of the given input in `Classic` notation to `tokens`, along with the byte
+/// ranges they span; if an error is encountered, `tokens` contains everything preceding it.
+fn spanned_classic_tokens(
+    input: &str,
+    tokens: &mut Vec<(Range<usize>, CToken)>,
+) -> Result<(), ParseError> {
+    let mut chars = skip_comments(positioned_chars(input)).peekable();
 
-    while let Some((i, c)) = chars.next() {
-        match c {
+    while let Some(positioned) = chars.next() {
+        let (offset, line, column, c) = positioned?;
+        let mut end = offset + c.len_utf8();
+
+        let token = match c {
             '\\' | 'λ' => {
                 let mut name = String::new();
-                for (i, c) in &mut chars {
+                for positioned in &mut chars {
+                    let (offset, line, column, c) = positioned?;
+                    end = offset + c.len_utf8();
                     if c == '.' {
                         break;
                     } else if c.is_alphabetic() {
                         name.push(c)
                     } else {
-                        return Err(InvalidCharacter((i, c)));
+                        return Err(invalid_character(line, column, c));
                     }
                 }
-                tokens.push(CLambda(name))
+                CLambda(name)
             }
-            '(' => tokens.push(CLparen),
-            ')' => tokens.push(CRparen),
+            '(' => CLparen,
+            ')' => CRparen,
             _ => {
                 if c.is_whitespace() {
-                    // ignore
+                    continue;
                 } else if c.is_alphabetic() {
                     let mut name = c.to_string();
-                    while let Some(&(_, c)) = chars.peek() {
+                    while let Some(&Ok((offset, .., c))) = chars.peek() {
                         if c.is_whitespace() || c == '(' || c == ')' {
                             break;
                         } else {
                             name.push(c);
+                            end = offset + c.len_utf8();
                             chars.next();
                         }
                     }
-                    tokens.push(CName(name))
+                    CName(name)
                 } else {
-                    return Err(InvalidCharacter((i, c)));
+                    return Err(invalid_character(line, column, c));
                 }
             }
-        }
+        };
+
+        tokens.push((offset..end, token));
     }
 
-    Ok(tokens)
+    Ok(())
 }
 
 #[doc(hidden)]
-pub fn convert_classic_tokens(tokens: &[CToken]) -> Vec<Token> {
-    _convert_classic_tokens(tokens, &mut Vec::with_capacity(tokens.len()), &mut 0)
+pub fn convert_classic_tokens(tokens: &[CToken]) -> Result<Vec<Token>, ParseError> {
+    let mut output = Vec::with_capacity(tokens.len());
+    _convert_classic_tokens(
+        tokens,
+        &mut Vec::with_capacity(tokens.len()),
+        &mut 0,
+        &mut output,
+    )?;
+
+    Ok(output)
 }
 
+/// Converts the `CToken`s starting at `pos` and pushes the results to `output`; every `CToken`
+/// results in exactly one `Token`, so if an error is encountered, the length of `output` is the
+/// position of the offending `CToken`.
 fn _convert_classic_tokens<'t>(
     tokens: &'t [CToken],
     stack: &mut Vec<&'t str>,
     pos: &mut usize,
-) -> Vec<Token> {
-    let mut output = Vec::with_capacity(tokens.len() - *pos);
+    output: &mut Vec<Token>,
+) -> Result<(), ParseError> {
     let mut inner_stack_count = 0;
 
     while let Some(token) = tokens.get(*pos) {
@@ -139,25 +423,25 @@ fn _convert_classic_tokens<'t>(
             CLparen => {
                 output.push(Lparen);
                 *pos += 1;
-                output.append(&mut _convert_classic_tokens(tokens, stack, pos));
+                _convert_classic_tokens(tokens, stack, pos, output)?;
             }
             CRparen => {
                 output.push(Rparen);
                 stack.truncate(stack.len() - inner_stack_count);
-                return output;
+                return Ok(());
             }
             CName(ref name) => {
                 if let Some(index) = stack.iter().rev().position(|t| t == name) {
                     output.push(Number(index + 1))
                 } else {
-                    output.push(Number(stack.len() + 1))
+                    return Err(FreeVariable(name.to_owned()));
                 }
             }
         }
         *pos += 1;
     }
 
-    output
+    Ok(())
 }
 
 #[derive(Debug, PartialEq)]
@@ -173,14 +457,20 @@ pub enum Expression {
 
 #[doc(hidden)]
 pub fn get_ast(tokens: &[Token]) -> Result<Expression, ParseError> {
-    _get_ast(tokens, &mut 0)
+    _get_ast(tokens, &mut 0, 0, &mut 0)
 }
 
-fn _get_ast(tokens: &[Token], pos: &mut usize) -> Result<Expression, ParseError> {
-    if tokens.is_empty() {
-        return Err(EmptyExpression);
-    }
-
+/// Builds the `Expression` starting at `pos`; on error, `pos` is the position of the offending
+/// `Token` (or the number of `Token`s if the input ended prematurely). `valid` is set to the
+/// length of the longest prefix of the `Token`s that forms a valid term.
+fn _get_ast(
+    tokens: &[Token],
+    pos: &mut usize,
+    depth: usize,
+    valid: &mut usize,
+) -> Result<Expression, ParseError> {
+    // an empty sequence or an abstraction without a body
+    let is_incomplete = |expr: &[Expression]| matches!(expr.last(), None | Some(Abstraction));
     let mut expr = Vec::new();
 
     while let Some(token) = tokens.get(*pos) {
@@ -189,25 +479,44 @@ fn _get_ast(tokens: &[Token], pos: &mut usize) -> Result<Expression, ParseError>
             Number(i) => expr.push(Variable(i)),
             Lparen => {
                 *pos += 1;
-                let subtree = _get_ast(tokens, pos)?;
+                let subtree = _get_ast(tokens, pos, depth + 1, valid)?;
                 expr.push(subtree);
             }
+            Rparen if depth == 0 => return Err(UnbalancedParens),
+            Rparen if is_incomplete(&expr) => return Err(EmptyExpression),
             Rparen => return Ok(Sequence(expr)),
         }
         *pos += 1;
+
+        if depth == 0 && !is_incomplete(&expr) {
+            *valid = *pos;
+        }
     }
 
-    Ok(Sequence(expr))
+    if depth != 0 {
+        Err(UnbalancedParens)
+    } else if is_incomplete(&expr) {
+        Err(EmptyExpression)
+    } else {
+        Ok(Sequence(expr))
+    }
 }
 
 /// Attempts to parse the input `&str` as a lambda `Term` encoded in the given `Notation`.
 ///
 /// - lambdas can be represented either with the greek letter (λ) or a backslash (\\ -
-/// less aesthetic, but only one byte in size)
-/// - the identifiers in `Classic` notation are `String`s of alphabetic Unicode characters
+///   less aesthetic, but only one byte in size)
+/// - the identifiers in `Classic` notation are `String`s of alphabetic Unicode characters; they
+///   are resolved to the innermost abstraction binding them and must not be free
 /// - `Classic` notation ignores whitespaces where unambiguous
-/// - the indices in the `DeBruijn` notation start with 1 and are hexadecimal digits
-/// - `DeBruijn` notation ignores all whitespaces (since indices > 15 are very unlikely)
+/// - the indices in the `DeBruijn` notation start with 1 and are decimal numbers; consecutive
+///   digits form a single index, so adjacent indices need to be separated with whitespace or
+///   parentheses: `λ11` is parsed as `λ(11)`, while `λ1 1` and `λ(1)1` as `λ(1 1)`
+/// - `DeBruijn` notation ignores whitespaces where unambiguous
+/// - applications are left-associative and the body of an abstraction extends as far right as
+///   possible, so `1 2 λ1 2` is parsed as `(1 2) (λ(1 2))`
+/// - `--` starts a comment that lasts until the end of the line and `{-` starts one that lasts
+///   until the nearest `-}` (block comments can't be nested); comments are treated as whitespace
 ///
 /// # Examples
 /// ```
@@ -217,7 +526,7 @@ fn _get_ast(tokens: &[Token], pos: &mut usize) -> Result<Expression, ParseError>
 /// assert_eq!(parse(&"λf.(λx.f (x x)) (λx.f (x x))", Classic), Ok(Y()));
 /// assert_eq!(parse(&"λƒ.(λℵ.ƒ(ℵ ℵ))(λℵ.ƒ(ℵ ℵ))", Classic),  Ok(Y()));
 ///
-/// assert_eq!(parse(  &"λλλ31(21)",     DeBruijn), Ok(S()));
+/// assert_eq!(parse(  &"λλλ3 1(2 1)",   DeBruijn), Ok(S()));
 /// assert_eq!(parse(&r#"\\\3 1 (2 1)"#, DeBruijn), Ok(S()));
 /// ```
 ///
@@ -225,20 +534,391 @@ fn _get_ast(tokens: &[Token], pos: &mut usize) -> Result<Expression, ParseError>
 ///
 /// Returns a `ParseError` when a lexing or syntax error is encountered.
 pub fn parse(input: &str, notation: Notation) -> Result<Term, ParseError> {
-    let tokens = if notation == DeBruijn {
+    parse_with(input, notation, ParserOptions::default())
+}
+
+/// Options altering the behavior of `parse_with()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// whether the De Bruijn indices in the `DeBruijn` notation start with 1 (the default) or
+    /// with 0; zero-based indices must be bound by an abstraction
+    pub one_indexed: bool,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        ParserOptions { one_indexed: true }
+    }
+}
+
+/// Attempts to parse the input `&str` as a lambda `Term` encoded in the given `Notation`, using
+/// the given `ParserOptions`. The parsed `Term` always uses indices starting with 1, like the
+/// rest of the library.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::parser::{parse_with, ParserOptions};
+/// use lambda_calculus::combinators::S;
+///
+/// let zero_indexed = ParserOptions { one_indexed: false };
+///
+/// assert_eq!(parse_with("λλλ2 0(1 0)", DeBruijn, zero_indexed), Ok(S()));
+/// assert_eq!(parse_with("λλλ3 1(2 1)", DeBruijn, ParserOptions::default()), Ok(S()));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `ParseError` when a lexing or syntax error is encountered; in the zero-indexed mode
+/// this includes an index that is not bound by any abstraction.
+pub fn parse_with(
+    input: &str,
+    notation: Notation,
+    options: ParserOptions,
+) -> Result<Term, ParseError> {
+    let mut tokens = if notation == DeBruijn {
         tokenize_dbr(input)?
     } else {
-        convert_classic_tokens(&tokenize_cla(input)?)
+        convert_classic_tokens(&tokenize_cla(input)?)?
     };
-    let ast = get_ast(&tokens)?;
+    let zero_indexed = notation == DeBruijn && !options.one_indexed;
+
+    if zero_indexed {
+        for token in &mut tokens {
+            if let Number(ref mut i) = *token {
+                *i = i
+                    .checked_add(1)
+                    .ok_or_else(|| FreeVariable(i.to_string()))?;
+            }
+        }
+    }
+
+    let term = fold_ast(get_ast(&tokens)?)?;
+
+    if zero_indexed {
+        if let Some(i) = first_free_variable(&term) {
+            return Err(FreeVariable((i - 1).to_string()));
+        }
+    }
 
-    let exprs = if let Sequence(exprs) = ast {
-        Ok(exprs)
+    Ok(term)
+}
+
+/// Returns the index of the first free variable of the given `Term`, if there is any.
+fn first_free_variable(term: &Term) -> Option<usize> {
+    let mut stack = vec![(0, term)];
+
+    while let Some((depth, term)) = stack.pop() {
+        match term {
+            Var(i) => {
+                if *i > depth {
+                    return Some(*i);
+                }
+            }
+            Abs(ref t) => stack.push((depth + 1, t)),
+            App(boxed) => {
+                let (ref lhs, ref rhs) = **boxed;
+                stack.push((depth, rhs));
+                stack.push((depth, lhs));
+            }
+        }
+    }
+
+    None
+}
+
+/// Attempts to parse the input `&str` as a lambda `Term` encoded in the given `Notation`; if the
+/// whole input can't be parsed, it parses its longest prefix that forms a valid term instead. The
+/// result is returned along with the byte offset where parsing stopped, i.e. the length of the
+/// input on success, the end of the parsed prefix if only a prefix is valid and the position of
+/// the error otherwise, so that e.g. a REPL can show how far the input is valid.
+///
+/// Prefixes end at token boundaries. The input is tokenized only once and the parsed prefix is
+/// the part of the input preceding the first error, so the cost is linear in its length.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::parser::{parse_partial, ParseError};
+/// use lambda_calculus::combinators::{I, K};
+///
+/// assert_eq!(parse_partial("λλ2", DeBruijn), (Ok(K()), 5));
+/// assert_eq!(parse_partial("λλ2 (1", DeBruijn), (Ok(K()), 5));
+/// assert_eq!(parse_partial("λx.x )", Classic), (Ok(I()), 5));
+/// assert_eq!(parse_partial("(λ1", DeBruijn), (Err(ParseError::UnbalancedParens), 4));
+/// assert_eq!(parse_partial("λx.y", Classic), (Err(ParseError::FreeVariable("y".into())), 4));
+/// ```
+///
+/// # Errors
+///
+/// Returns the first `ParseError` encountered in the input, along with its byte offset, if none
+/// of its prefixes is a valid term; errors caused by the input ending prematurely, e.g. with an
+/// unclosed parenthesis, are located at its end.
+pub fn parse_partial(input: &str, notation: Notation) -> (Result<Term, ParseError>, usize) {
+    let mut spans = Vec::new();
+    let mut tokens = Vec::new();
+    // the error that ended the tokenization, along with its offset
+    let mut stop = None;
+
+    if notation == DeBruijn {
+        for token in spanned_tokens(input) {
+            match token {
+                Ok((span, token)) => {
+                    spans.push(span);
+                    tokens.push(token);
+                }
+                Err(error) => {
+                    let offset = error_offset(input, &error);
+                    stop = Some((error, offset));
+                }
+            }
+        }
     } else {
-        Err(InvalidExpression)
+        let mut spanned = Vec::new();
+        if let Err(error) = spanned_classic_tokens(input, &mut spanned) {
+            let offset = error_offset(input, &error);
+            stop = Some((error, offset));
+        }
+        let classic_tokens: Vec<_>;
+        (spans, classic_tokens) = spanned.into_iter().unzip();
+
+        let mut stack = Vec::with_capacity(classic_tokens.len());
+        if let Err(error) =
+            _convert_classic_tokens(&classic_tokens, &mut stack, &mut 0, &mut tokens)
+        {
+            stop = Some((error, spans[tokens.len()].start));
+        }
+    }
+
+    let (mut pos, mut valid) = (0, 0);
+    let (error, offset) = match (_get_ast(&tokens, &mut pos, 0, &mut valid), stop) {
+        (Ok(ast), None) => return (fold_ast(ast), input.len()),
+        // the syntax is valid up to the error that ended the tokenization
+        (Ok(_), Some(stop)) => stop,
+        (Err(_), Some(stop)) if pos == tokens.len() => stop,
+        (Err(error), _) => (error, spans.get(pos).map_or(input.len(), |span| span.start)),
     };
 
-    fold_exprs(&exprs?)
+    if valid == 0 {
+        (Err(error), offset)
+    } else {
+        let prefix = get_ast(&tokens[..valid]).and_then(fold_ast);
+        (prefix, spans[valid - 1].end)
+    }
+}
+
+/// Returns the byte offset of the position contained in a lexical `ParseError`, or the length of
+/// the input for other kinds of errors.
+fn error_offset(input: &str, error: &ParseError) -> usize {
+    let (line, column) = match *error {
+        InvalidCharacter { line, column, .. }
+        | IndexOverflow { line, column }
+        | UnterminatedComment { line, column } => (line, column),
+        _ => return input.len(),
+    };
+
+    positioned_chars(input)
+        .find(|&(_, l, c, _)| (l, c) == (line, column))
+        .map_or(input.len(), |(offset, ..)| offset)
+}
+
+/// Attempts to parse the input `&str` as a sequence of lambda `Term`s encoded in the given
+/// `Notation` and separated with semicolons (`;`). Segments that are empty or contain only
+/// whitespace and comments are skipped. The rules for individual terms are the same as in
+/// `parse()`.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::parser::parse_many;
+/// use lambda_calculus::combinators::{I, K, S};
+///
+/// let library = "
+///     -- the SKI basis
+///     λx.λy.λz.x z (y z);
+///     λx.λy.x;
+///     λx.x;
+/// ";
+///
+/// assert_eq!(parse_many(library, Classic), Ok(vec![S(), K(), I()]));
+/// ```
+///
+/// # Errors
+///
+/// Returns the index of the offending term (counting only the non-empty segments) along with
+/// the `ParseError` it caused. The positions contained in the error are relative to the whole
+/// input.
+pub fn parse_many(input: &str, notation: Notation) -> Result<Vec<Term>, (usize, ParseError)> {
+    let mut stripped = String::with_capacity(input.len());
+    let is_term = |segment: &str| !segment.trim().is_empty();
+
+    if let Err(error) = blank_comments(input, &mut stripped) {
+        let segments: Vec<&str> = stripped.split(';').collect();
+        let index = segments[..segments.len() - 1]
+            .iter()
+            .filter(|segment| is_term(segment))
+            .count();
+        return Err((index, error));
+    }
+
+    let mut terms = Vec::new();
+    let (mut line, mut column) = (1, 0); // the position preceding the current segment
+
+    for segment in stripped.split(';') {
+        if is_term(segment) {
+            let term = parse(segment, notation)
+                .map_err(|error| (terms.len(), shift_position(error, line, column)))?;
+            terms.push(term);
+        }
+
+        for c in segment.chars() {
+            if c == '\n' {
+                line += 1;
+                column = 0;
+            } else {
+                column += 1;
+            }
+        }
+        column += 1; // the separator
+    }
+
+    Ok(terms)
+}
+
+/// Moves the position contained in a `ParseError` that was produced for a part of the input
+/// preceded by the given line and column.
+fn shift_position(error: ParseError, line: usize, column: usize) -> ParseError {
+    let shift = |l: usize, c: usize| (l + line - 1, if l == 1 { c + column } else { c });
+
+    match error {
+        InvalidCharacter {
+            line: l,
+            column: c,
+            character,
+        } => {
+            let (line, column) = shift(l, c);
+            InvalidCharacter {
+                line,
+                column,
+                character,
+            }
+        }
+        UnterminatedComment { line: l, column: c } => {
+            let (line, column) = shift(l, c);
+            UnterminatedComment { line, column }
+        }
+        IndexOverflow { line: l, column: c } => {
+            let (line, column) = shift(l, c);
+            IndexOverflow { line, column }
+        }
+        error => error,
+    }
+}
+
+/// Attempts to decode a lambda `Term` from a `&str` containing its
+/// [binary lambda calculus](https://tromp.github.io/cl/Binary_lambda_calculus.html) encoding,
+/// i.e. a sequence of `0`s and `1`s where `00` denotes an abstraction, `01` an application of the
+/// two terms that follow it and `1ⁿ0` a variable with the De Bruijn index `n`. Whitespaces are
+/// ignored.
+///
+/// # Examples
+/// ```
+/// use lambda_calculus::parser::from_blc;
+/// use lambda_calculus::combinators::{I, K, S};
+///
+/// assert_eq!(from_blc("0010"), Ok(I()));
+/// assert_eq!(from_blc("00 00 110"), Ok(K()));
+/// assert_eq!(from_blc("00000001011110100111010"), Ok(S()));
+/// ```
+///
+/// # Errors
+///
+/// Returns a `ParseError` if the input contains characters other than `0`, `1` and whitespaces,
+/// if it ends in the middle of a term or if anything follows the encoded term.
+pub fn from_blc(bits: &str) -> Result<Term, ParseError> {
+    let mut bits = positioned_chars(bits)
+        .filter(|&(.., c)| !c.is_whitespace())
+        .map(|(_, line, column, c)| match c {
+            '0' => Ok(false),
+            '1' => Ok(true),
+            _ => Err(invalid_character(line, column, c)),
+        })
+        .peekable();
+
+    if bits.peek().is_none() {
+        return Err(EmptyExpression);
+    }
+
+    let term = _from_blc(&mut bits)?;
+
+    match bits.next() {
+        None => Ok(term),
+        Some(Err(error)) => Err(error),
+        Some(Ok(_)) => Err(InvalidExpression),
+    }
+}
+
+fn _from_blc<I: Iterator<Item = Result<bool, ParseError>>>(
+    bits: &mut I,
+) -> Result<Term, ParseError> {
+    let mut next_bit = || bits.next().unwrap_or(Err(InvalidExpression));
+
+    if next_bit()? {
+        let mut index = 1;
+        while next_bit()? {
+            index += 1;
+        }
+        Ok(Var(index))
+    } else if next_bit()? {
+        let lhs = _from_blc(bits)?;
+        let rhs = _from_blc(bits)?;
+        Ok(app(lhs, rhs))
+    } else {
+        Ok(abs(_from_blc(bits)?))
+    }
+}
+
+/// Attempts to decode a lambda `Term` from a `&str` containing a
+/// [Jot](https://en.wikipedia.org/wiki/Iota_and_Jot#Jot) program, i.e. a sequence of `0`s and
+/// `1`s, where the empty program denotes `I`, appending a `0` to a program `w` denotes `w S K`
+/// and appending a `1` denotes `λxy.w (x y)`. Whitespaces are ignored. The resulting `Term` is not
+/// reduced.
+///
+/// # Examples
+/// ```
+/// use lambda_calculus::parser::from_jot;
+/// use lambda_calculus::combinators::{I, K, S};
+/// use lambda_calculus::*;
+///
+/// assert_eq!(from_jot(""), Ok(I()));
+/// assert_eq!(from_jot("0"), Ok(app!(I(), S(), K())));
+/// assert_eq!(beta(from_jot("11100").unwrap(), NOR, 0), K());
+/// assert_eq!(beta(from_jot("11111000").unwrap(), NOR, 0), S());
+/// ```
+///
+/// # Errors
+///
+/// Returns a `ParseError` if the input contains characters other than `0`, `1` and whitespaces.
+pub fn from_jot(bits: &str) -> Result<Term, ParseError> {
+    let mut term = I();
+
+    for (_, line, column, c) in positioned_chars(bits).filter(|&(.., c)| !c.is_whitespace()) {
+        term = match c {
+            '0' => app!(term, S(), K()),
+            '1' => abs!(2, app(term, app(Var(2), Var(1)))),
+            _ => return Err(invalid_character(line, column, c)),
+        }
+    }
+
+    Ok(term)
+}
+
+fn fold_ast(ast: Expression) -> Result<Term, ParseError> {
+    if let Sequence(exprs) = ast {
+        fold_exprs(&exprs)
+    } else {
+        Err(InvalidExpression)
+    }
 }
 
 #[doc(hidden)]
@@ -246,9 +926,14 @@ pub fn fold_exprs(exprs: &[Expression]) -> Result<Term, ParseError> {
     let mut depth = 0;
     let mut output = Vec::new();
 
-    for expr in exprs.iter() {
+    for (i, expr) in exprs.iter().enumerate() {
         match *expr {
-            Abstraction => depth += 1,
+            Abstraction if output.is_empty() => depth += 1,
+            Abstraction => {
+                // an abstraction extends as far right as possible
+                output.push(fold_exprs(&exprs[i..])?);
+                break;
+            }
             Variable(i) => output.push(Var(i)),
             Sequence(ref exprs) => output.push(fold_exprs(exprs)?),
         }
@@ -273,11 +958,47 @@ fn fold_terms(mut terms: Vec<Term>) -> Result<Term, ParseError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::term::LAMBDA;
 
     #[test]
     fn tokenization_error() {
-        assert_eq!(tokenize_dbr("λλx2"), Err(InvalidCharacter((2, 'x'))));
-        assert_eq!(tokenize_cla("λa.λb a"), Err(InvalidCharacter((5, ' '))));
+        assert_eq!(tokenize_dbr("λλx2"), Err(invalid_character(1, 3, 'x')));
+        assert_eq!(tokenize_cla("λa.λb a"), Err(invalid_character(1, 6, ' ')));
+    }
+
+    #[test]
+    fn tokenization_error_position() {
+        assert_eq!(
+            tokenize_dbr("λλ\n  2 1\n  (λ1 x)"),
+            Err(invalid_character(3, 7, 'x'))
+        );
+        assert_eq!(
+            tokenize_cla("λa.\nλb.\n  λc_.c"),
+            Err(invalid_character(3, 5, '_'))
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            invalid_character(2, 4, 'x').to_string(),
+            "invalid character 'x' at line 2 column 4"
+        );
+        assert_eq!(InvalidExpression.to_string(), "invalid expression");
+        assert_eq!(EmptyExpression.to_string(), "empty expression");
+        assert_eq!(UnbalancedParens.to_string(), "unbalanced parentheses");
+        assert_eq!(FreeVariable("y".into()).to_string(), "free variable 'y'");
+        assert_eq!(
+            UnterminatedComment { line: 1, column: 3 }.to_string(),
+            "unterminated comment opened at line 1 column 3"
+        );
+        assert_eq!(
+            IndexOverflow { line: 2, column: 5 }.to_string(),
+            "index too large at line 2 column 5"
+        );
+
+        let boxed: Box<dyn std::error::Error> = Box::new(UnbalancedParens);
+        assert_eq!(boxed.to_string(), "unbalanced parentheses");
     }
 
     #[test]
@@ -322,8 +1043,8 @@ mod tests {
 
     #[test]
     fn tokenization_success_classic() {
-        let blc_dbr = "(λ11)(λλλ1(λλλλ3(λ5(3(λ2(3(λλ3(λ123)))(4(λ4(λ31(21))))))(1(2(λ12))\
-            (λ4(λ4(λ2(14)))5))))(33)2)(λ1((λ11)(λ11)))";
+        let blc_dbr = "(λ1 1)(λλλ1(λλλλ3(λ5(3(λ2(3(λλ3(λ1 2 3)))(4(λ4(λ3 1(2 1))))))(1(2(λ1 2))\
+            (λ4(λ4(λ2(1 4)))5))))(3 3)2)(λ1((λ1 1)(λ1 1)))";
         let blc_cla = parse(blc_dbr, DeBruijn).unwrap().to_string();
 
         let tokens_cla = tokenize_cla(&blc_cla);
@@ -334,21 +1055,281 @@ mod tests {
 
         assert_eq!(
             convert_classic_tokens(&tokens_cla.unwrap()),
-            tokens_dbr.unwrap()
+            Ok(tokens_dbr.unwrap())
+        );
+    }
+
+    #[test]
+    fn parse_classic_identifiers() {
+        assert_eq!(parse("λx.λx.x", Classic), Ok(abs!(2, Var(1))));
+        assert_eq!(
+            parse("λx.λy.x y", Classic),
+            Ok(abs!(2, app(Var(2), Var(1))))
+        );
+        assert_eq!(
+            parse("λfoo.λbar.foo (foo bar)", Classic),
+            Ok(abs!(2, app(Var(2), app(Var(2), Var(1)))))
+        );
+        assert_eq!(
+            parse(r#"(\f.\x.f (f x))"#, Classic),
+            Ok(abs!(2, app(Var(2), app(Var(2), Var(1)))))
+        );
+    }
+
+    #[test]
+    fn parse_classic_free_variable() {
+        assert_eq!(parse("λx.y", Classic), Err(FreeVariable("y".into())));
+        assert_eq!(parse("(λx.x) x", Classic), Err(FreeVariable("x".into())));
+    }
+
+    #[test]
+    fn multi_digit_indices() {
+        assert_eq!(
+            tokenize_dbr("λ 10 1"),
+            Ok(vec![Lambda, Number(10), Number(1)])
+        );
+        assert_eq!(parse("λ 10", DeBruijn), Ok(abs(Var(10))));
+        assert_eq!(
+            parse(&format!("{:?}", abs!(12, app(Var(12), Var(1)))), DeBruijn),
+            Ok(abs!(12, app(Var(12), Var(1))))
+        );
+
+        let too_large = format!("λ 1 {}0", usize::MAX);
+        assert_eq!(
+            tokenize_dbr(&too_large),
+            Err(IndexOverflow { line: 1, column: 5 })
+        );
+        assert_eq!(
+            parse_many(&format!("λ1;\n{}", too_large), DeBruijn),
+            Err((1, IndexOverflow { line: 2, column: 5 }))
+        );
+    }
+
+    #[test]
+    fn adjacent_indices() {
+        // consecutive digits always form a single index
+        assert_eq!(parse("λ 11", DeBruijn), Ok(abs(Var(11))));
+        assert_eq!(parse("λ11", DeBruijn), Ok(abs(Var(11))));
+        // separate indices need whitespace or parentheses between them
+        assert_eq!(parse("λ 1 1", DeBruijn), Ok(abs(app(Var(1), Var(1)))));
+        assert_eq!(parse("λ(1)1", DeBruijn), Ok(abs(app(Var(1), Var(1)))));
+        assert_eq!(parse("λ1(1)", DeBruijn), Ok(abs(app(Var(1), Var(1)))));
+        assert_eq!(
+            tokenize_dbr("λ(1)1"),
+            Ok(vec![Lambda, Lparen, Number(1), Rparen, Number(1)])
+        );
+        // the formatted terms are separated accordingly
+        assert_eq!(
+            format!("{:?}", abs(app(Var(1), Var(1)))),
+            format!("{}1 1", LAMBDA)
+        );
+        assert_eq!(format!("{:?}", abs(Var(11))), format!("{}11", LAMBDA));
+    }
+
+    #[test]
+    fn comments() {
+        let commented = "-- the S combinator\n\
+            λ {- x -} λ {- y -} λ {- z -}\n\
+            3 1 -- x z\n\
+            (2 1) -- (y z)";
+        assert_eq!(parse(commented, DeBruijn), parse("λλλ3 1(2 1)", DeBruijn));
+
+        let commented = "λx.{- the body -}x--identity";
+        assert_eq!(parse(commented, Classic), parse("λx.x", Classic));
+
+        assert_eq!(parse("1{--}1", DeBruijn), parse("1 1", DeBruijn));
+        assert_eq!(parse("{-}-}1", DeBruijn), parse("1", DeBruijn));
+    }
+
+    #[test]
+    fn unterminated_comment() {
+        assert_eq!(
+            parse("λ1\n  {- no end -", DeBruijn),
+            Err(UnterminatedComment { line: 2, column: 3 })
+        );
+        assert_eq!(
+            parse("1 {- {- -} -}", DeBruijn),
+            Err(InvalidCharacter {
+                line: 1,
+                column: 12,
+                character: '-'
+            })
+        );
+    }
+
+    #[test]
+    fn parse_many_terms() {
+        assert_eq!(parse_many("", DeBruijn), Ok(vec![]));
+        assert_eq!(parse_many(" ;; {- ; -} ;", DeBruijn), Ok(vec![]));
+        assert_eq!(
+            parse_many("λ1; λλ2 -- ;\n;λλ1", DeBruijn),
+            Ok(vec![abs(Var(1)), abs!(2, Var(2)), abs!(2, Var(1))])
+        );
+        assert_eq!(
+            parse_many("λx.x;;λx.y", Classic),
+            Err((1, FreeVariable("y".into())))
+        );
+        assert_eq!(parse_many("λ1;λ", DeBruijn), Err((1, EmptyExpression)));
+    }
+
+    #[test]
+    fn parse_many_error_position() {
+        assert_eq!(
+            parse_many("λ1; λλ2;\n λ1;  λx", DeBruijn),
+            Err((
+                3,
+                InvalidCharacter {
+                    line: 2,
+                    column: 8,
+                    character: 'x'
+                }
+            ))
+        );
+        assert_eq!(
+            parse_many("λ1;\n;λ 1 {- ; -}; 2 {-", DeBruijn),
+            Err((
+                2,
+                UnterminatedComment {
+                    line: 2,
+                    column: 17
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn unbalanced_parens() {
+        assert_eq!(parse("λ(1", DeBruijn), Err(UnbalancedParens));
+        assert_eq!(parse("(λ1 (1 1)", DeBruijn), Err(UnbalancedParens));
+        assert_eq!(parse("λ1)", DeBruijn), Err(UnbalancedParens));
+        assert_eq!(parse("(λ1))(1 1)", DeBruijn), Err(UnbalancedParens));
+        assert_eq!(parse("λx.(x x", Classic), Err(UnbalancedParens));
+        assert_eq!(parse("λx.x x)", Classic), Err(UnbalancedParens));
+        assert_eq!(
+            parse("((λ1)(λ1))", DeBruijn),
+            Ok(app(abs(Var(1)), abs(Var(1))))
+        );
+    }
+
+    #[test]
+    fn blc_decoding() {
+        assert_eq!(from_blc("10"), Ok(Var(1)));
+        assert_eq!(from_blc("1110"), Ok(Var(3)));
+        assert_eq!(from_blc("01 10 110"), Ok(app(Var(1), Var(2))));
+        assert_eq!(
+            from_blc("01 0000110 0010"),
+            Ok(app(abs!(2, Var(2)), abs(Var(1))))
+        );
+    }
+
+    #[test]
+    fn blc_decoding_errors() {
+        assert_eq!(from_blc(""), Err(EmptyExpression));
+        assert_eq!(from_blc(" \n"), Err(EmptyExpression));
+        assert_eq!(from_blc("00"), Err(InvalidExpression));
+        assert_eq!(from_blc("0111"), Err(InvalidExpression));
+        assert_eq!(from_blc("001"), Err(InvalidExpression));
+        assert_eq!(from_blc("00101"), Err(InvalidExpression));
+        assert_eq!(from_blc("0021"), Err(invalid_character(1, 3, '2')));
+        assert_eq!(from_blc("0010\n x"), Err(invalid_character(2, 2, 'x')));
+    }
+
+    #[test]
+    fn abstraction_as_last_argument() {
+        assert_eq!(
+            parse("1 λ1 2", DeBruijn),
+            Ok(app(Var(1), abs(app(Var(1), Var(2)))))
+        );
+        assert_eq!(
+            parse("λ1 (λ1) λ2", DeBruijn),
+            Ok(abs(app!(Var(1), abs(Var(1)), abs(Var(2)))))
+        );
+        assert_eq!(
+            parse("λa.a λb.b", Classic),
+            Ok(abs(app(Var(1), abs(Var(1)))))
+        );
+    }
+
+    #[test]
+    fn jot_decoding() {
+        use crate::reduction::{beta, Order::NOR};
+
+        assert_eq!(from_jot(" "), Ok(I()));
+        assert_eq!(beta(from_jot("010").unwrap(), NOR, 0), I());
+        assert_eq!(beta(from_jot("0 1 0").unwrap(), NOR, 0), I());
+        assert_eq!(
+            beta(from_jot("1").unwrap(), NOR, 0),
+            abs!(2, app(Var(2), Var(1)))
+        );
+        assert_eq!(from_jot("012"), Err(invalid_character(1, 3, '2')));
+    }
+
+    #[test]
+    fn token_iterator() {
+        let input = "-- comment\n\\λ1 2";
+        assert_eq!(
+            tokens(input).collect::<Vec<_>>(),
+            vec![
+                Ok((11, Lambda)),
+                Ok((12, Lambda)),
+                Ok((14, Number(1))),
+                Ok((16, Number(2)))
+            ]
+        );
+
+        let mut iter = tokens("λ1 x 2");
+        assert_eq!(iter.next(), Some(Ok((0, Lambda))));
+        assert_eq!(iter.next(), Some(Ok((2, Number(1)))));
+        assert_eq!(iter.next(), Some(Err(invalid_character(1, 4, 'x'))));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = tokens("1 {- 2");
+        assert_eq!(iter.next(), Some(Ok((0, Number(1)))));
+        assert_eq!(
+            iter.next(),
+            Some(Err(UnterminatedComment { line: 1, column: 3 }))
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn zero_indexed_parsing() {
+        let zero_indexed = ParserOptions { one_indexed: false };
+
+        assert_eq!(parse_with("λ0", DeBruijn, zero_indexed), Ok(abs(Var(1))));
+        assert_eq!(
+            parse_with("λλ1(λ2 0)", DeBruijn, zero_indexed),
+            parse("λλ2(λ3 1)", DeBruijn)
+        );
+        assert_eq!(
+            parse_with("λx.x", Classic, zero_indexed),
+            parse("λx.x", Classic)
+        );
+        assert_eq!(
+            parse_with("λ1", DeBruijn, zero_indexed),
+            Err(FreeVariable("1".into()))
+        );
+        assert_eq!(
+            parse_with("(λ0) 0", DeBruijn, zero_indexed),
+            Err(FreeVariable("0".into()))
+        );
+        assert_eq!(
+            parse_with("λ1", DeBruijn, ParserOptions::default()),
+            Ok(abs(Var(1)))
         );
     }
 
     #[test]
     fn alternative_lambda_parsing() {
         assert_eq!(
-            parse(r#"\\\2(321)"#, DeBruijn),
-            parse("λλλ2(321)", DeBruijn)
+            parse(r#"\\\2(3 2 1)"#, DeBruijn),
+            parse("λλλ2(3 2 1)", DeBruijn)
         )
     }
 
     #[test]
     fn succ_ast() {
-        let tokens = tokenize_dbr("λλλ2(321)").unwrap();
+        let tokens = tokenize_dbr("λλλ2(3 2 1)").unwrap();
         let ast = get_ast(&tokens);
 
         assert_eq!(
@@ -365,7 +1346,7 @@ mod tests {
 
     #[test]
     fn parse_y() {
-        let y = "λ(λ2(11))(λ2(11))";
+        let y = "λ(λ2(1 1))(λ2(1 1))";
         assert_eq!(
             parse(y, DeBruijn).unwrap(),
             abs(app(
@@ -377,7 +1358,7 @@ mod tests {
 
     #[test]
     fn parse_quine() {
-        let quine = "λ1((λ11)(λλλλλ14(3(55)2)))1";
+        let quine = "λ1((λ1 1)(λλλλλ1 4(3(5 5)2)))1";
         assert_eq!(
             parse(quine, DeBruijn).unwrap(),
             abs(app(
@@ -401,8 +1382,8 @@ mod tests {
 
     #[test]
     fn parse_blc() {
-        let blc = "(λ11)(λλλ1(λλλλ3(λ5(3(λ2(3(λλ3(λ123)))(4(λ4(λ31(21))))))(1(2(λ12))\
-                   (λ4(λ4(λ2(14)))5))))(33)2)(λ1((λ11)(λ11)))";
+        let blc = "(λ1 1)(λλλ1(λλλλ3(λ5(3(λ2(3(λλ3(λ1 2 3)))(4(λ4(λ3 1(2 1))))))(1(2(λ1 2))\
+                   (λ4(λ4(λ2(1 4)))5))))(3 3)2)(λ1((λ1 1)(λ1 1)))";
         assert_eq!(
             parse(blc, DeBruijn).unwrap(),
             app(
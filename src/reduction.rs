@@ -1,16 +1,27 @@
 //! [β-reduction](https://en.wikipedia.org/wiki/Beta_normal_form) for lambda `Term`s
-
+//!
+//! The reduction functions take a `limit` on the number of reduction steps: `0` means that there
+//! is no limit, i.e. the `Term` is reduced as far as the evaluation `Order` goes (which may never
+//! end for terms without a normal form), while any other value is the maximum number of steps to
+//! perform. A limit of `0` is therefore not a request for zero steps; to leave a `Term` unreduced
+//! simply don't reduce it.
+
+pub use self::memoized::normalize_memoized;
+pub use self::shared::normalize_shared;
 pub use self::Order::*;
 use crate::term::Term::*;
-use crate::term::{Term, TermError};
+use crate::term::{abs, app, Term, TermError};
 use std::{cmp, fmt, mem};
 
+mod memoized;
+mod shared;
+
 /// The [evaluation order](http://www.cs.cornell.edu/courses/cs6110/2014sp/Handouts/Sestoft.pdf) of
 /// β-reductions.
 ///
 /// - the `NOR`, `HNO`, `APP` and `HAP` orders reduce expressions to their normal form
 /// - the `APP` order will fail to fully reduce expressions containing terms without a normal form,
-/// e.g. the `Y` combinator (they will expand forever)
+///   e.g. the `Y` combinator (they will expand forever)
 /// - the `CBN` order reduces to weak head normal form
 /// - the `CBV` order reduces to weak normal form
 /// - the `HSP` order reduces to head normal form
@@ -33,6 +44,28 @@ pub enum Order {
     HAP,
 }
 
+/// An error that can be returned by the reduction functions that enforce additional bounds.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReductionError {
+    /// the number of nodes of the reduced term exceeded the allowed size
+    SizeExceeded,
+    /// the limit on the number of reductions was reached before the term was fully reduced
+    LimitReached,
+    /// a reduction step left the term unchanged, so it would be reduced forever
+    Diverges,
+}
+
+/// A step on the path from a `Term` to one of its subterms.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Side {
+    /// the left-hand side of an application
+    Left,
+    /// the right-hand side of an application
+    Right,
+    /// the body of an abstraction
+    Body,
+}
+
 /// Performs β-reduction on a `Term` with the specified evaluation `Order` and an optional limit on
 /// the number of reductions (`0` means no limit) and returns the reduced `Term`.
 ///
@@ -51,6 +84,485 @@ pub fn beta(mut term: Term, order: Order, limit: usize) -> Term {
     term
 }
 
+/// Performs β-reduction on a `Term` with the specified evaluation `Order` and an optional limit on
+/// the number of reductions (`0` means no limit) and returns the reduced `Term` along with the
+/// number of performed reductions; if it is equal to a non-zero `limit`, the `Term` may not be
+/// fully reduced. This is the by-value counterpart of `Term::reduce`.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::beta_counted;
+/// use lambda_calculus::combinators::{I, O};
+///
+/// assert_eq!(beta_counted(app(I(), I()), NOR, 0), (I(), 1));
+/// assert_eq!(beta_counted(O(), NOR, 5), (O(), 5));
+/// ```
+pub fn beta_counted(mut term: Term, order: Order, limit: usize) -> (Term, usize) {
+    let count = term.reduce(order, limit);
+    (term, count)
+}
+
+/// Performs β-reduction on a `Term` with the specified evaluation `Order` and an optional limit on
+/// the number of reductions (`0` means no limit), calling `f` with the intermediate `Term` and
+/// the number of reductions performed so far: first before any reductions and then after each
+/// one of them. Returns the reduced `Term`.
+///
+/// Each reduction step involves a new traversal of the `Term`, so `beta` should be preferred when
+/// the intermediate terms are not needed.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::beta_trace;
+///
+/// let expr = parse(&"(λa.λb.b a) (λa.a) (λa.a a)", Classic).unwrap();
+/// let mut steps = Vec::new();
+///
+/// let reduced = beta_trace(expr, NOR, 0, |term, step| steps.push(format!("{}: {}", step, term)));
+///
+/// assert_eq!(reduced, parse(&"λa.a", Classic).unwrap());
+/// assert_eq!(
+///     steps,
+///     vec![
+///         "0: (λa.λb.b a) (λa.a) (λa.a a)",
+///         "1: (λa.a (λb.b)) (λa.a a)",
+///         "2: (λa.a a) (λa.a)",
+///         "3: (λa.a) (λa.a)",
+///         "4: λa.a",
+///     ]
+/// );
+/// ```
+pub fn beta_trace<F: FnMut(&Term, usize)>(
+    mut term: Term,
+    order: Order,
+    limit: usize,
+    mut f: F,
+) -> Term {
+    let mut count = 0;
+
+    f(&term, count);
+
+    while (limit == 0 || count < limit) && term.reduce(order, 1) != 0 {
+        count += 1;
+        f(&term, count);
+    }
+
+    term
+}
+
+/// Performs β-reduction on a `Term` with the specified evaluation `Order`, an optional limit on
+/// the number of reductions (`0` means no limit) and a limit on its size (the number of its
+/// variables, abstractions and applications), checked before the reduction and after every step.
+/// Returns the reduced `Term`.
+///
+/// The size is only computed once; afterwards it is updated with the difference caused by each
+/// contraction, which only depends on the size of the argument and the number of occurrences of
+/// the bound variable in the body of the redex.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::{beta_bounded, ReductionError};
+/// use lambda_calculus::combinators::I;
+///
+/// let growing = parse(&"(λ1 1 1)(λ1 1 1)", DeBruijn).unwrap();
+///
+/// assert_eq!(beta_bounded(app(I(), I()), NOR, 0, 10), Ok(I()));
+/// assert_eq!(beta_bounded(growing, NOR, 0, 100), Err(ReductionError::SizeExceeded));
+/// ```
+///
+/// # Errors
+///
+/// Returns `ReductionError::SizeExceeded` if the `Term` grows beyond `size_limit` nodes.
+pub fn beta_bounded(
+    mut term: Term,
+    order: Order,
+    step_limit: usize,
+    size_limit: usize,
+) -> Result<Term, ReductionError> {
+    let mut size = term.size();
+    let mut count = 0;
+
+    while size <= size_limit {
+        if step_limit != 0 && count == step_limit {
+            return Ok(term);
+        }
+
+        let Some(path) = next_redex_path(&term, order) else {
+            return Ok(term);
+        };
+        let redex = path.iter().fold(&mut term, |term, side| match side {
+            Side::Left => term.lhs_mut().unwrap(), // safe; the path leads to a redex
+            Side::Right => term.rhs_mut().unwrap(), // ditto
+            Side::Body => term.unabs_mut().unwrap(), // ditto
+        });
+
+        // (λ M) N becomes M with each occurrence of the bound variable replaced by N
+        let (abstraction, argument) = redex.unapp_ref().unwrap(); // ditto
+        let occurrences = abstraction.unabs_ref().unwrap().occurrences(1); // ditto
+        let argument_size = argument.size();
+        size = size - (argument_size + 2 + occurrences) + occurrences * argument_size;
+
+        redex.eval(&mut count);
+    }
+
+    Err(ReductionError::SizeExceeded)
+}
+
+/// Performs β-reduction on a `Term` with the specified evaluation `Order` and an optional limit on
+/// the number of reductions (`0` means no limit) and returns the reduced `Term`, but unlike `beta`
+/// it doesn't return terms that are not fully reduced.
+///
+/// A step that leaves the `Term` unchanged (like in the case of `(λx.x x) (λx.x x)`) is treated as
+/// a sign of divergence; other kinds of infinite reductions can only be stopped by the limit.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::{try_normalize, ReductionError};
+/// use lambda_calculus::combinators::{I, O};
+///
+/// assert_eq!(try_normalize(app(I(), I()), NOR, 1), Ok(I()));
+/// assert_eq!(try_normalize(app(I(), app(I(), I())), NOR, 1), Err(ReductionError::LimitReached));
+/// assert_eq!(try_normalize(O(), NOR, 0), Err(ReductionError::Diverges));
+/// ```
+///
+/// # Errors
+///
+/// Returns `ReductionError::LimitReached` if the `Term` is still reducible after `limit`
+/// reductions and `ReductionError::Diverges` if a reduction step doesn't change it.
+pub fn try_normalize(mut term: Term, order: Order, limit: usize) -> Result<Term, ReductionError> {
+    let mut count = 0;
+
+    loop {
+        let previous = term.clone();
+
+        if term.reduce(order, 1) == 0 {
+            return Ok(term);
+        }
+        if term == previous {
+            return Err(ReductionError::Diverges);
+        }
+
+        count += 1;
+
+        if limit != 0 && count == limit {
+            return if term.clone().reduce(order, 1) == 0 {
+                Ok(term)
+            } else {
+                Err(ReductionError::LimitReached)
+            };
+        }
+    }
+}
+
+/// Reduces a `Term` to its
+/// [weak head normal form](https://en.wikipedia.org/wiki/Lambda_calculus_definition#Normal_forms),
+/// i.e. until it is an abstraction or a variable applied to any number of arguments, with an
+/// optional limit on the number of reductions (`0` means no limit). Neither the bodies of
+/// abstractions nor the arguments are reduced; this is equivalent to `beta(term, CBN, limit)`.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::whnf;
+///
+/// let abstraction = parse(&"λx.(λy.y) x", Classic).unwrap();
+/// let head_redex  = parse(&"(λx.x) (λx.(λy.y) x)", Classic).unwrap();
+///
+/// assert_eq!(whnf(abstraction.clone(), 0), abstraction);
+/// assert_eq!(whnf(head_redex, 0), abstraction);
+/// ```
+pub fn whnf(term: Term, limit: usize) -> Term {
+    beta(term, CBN, limit)
+}
+
+/// Performs [η-reduction](https://en.wikipedia.org/wiki/Lambda_calculus#%CE%B7-reduction) on a
+/// `Term` with an optional limit on the number of reductions (`0` means no limit) and returns
+/// the reduced `Term`.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::eta;
+///
+/// let expr = parse(&"λa.λb.(λc.a c) b", Classic).unwrap();
+///
+/// assert_eq!(eta(expr.clone(), 1), parse(&"λa.λc.a c", Classic).unwrap());
+/// assert_eq!(eta(expr, 0), parse(&"λa.a", Classic).unwrap());
+/// ```
+pub fn eta(mut term: Term, limit: usize) -> Term {
+    term.eta_reduce(limit);
+    term
+}
+
+/// Performs β-reduction with the specified evaluation `Order` interleaved with η-reduction on a
+/// `Term` until neither of them is applicable, with an optional limit on the total number of
+/// reductions (`0` means no limit), and returns the reduced `Term`.
+///
+/// The result can differ from the β-normal form of the same `Term`; e.g. the Church-encoded number
+/// one, `λf.λx.f x`, is in β-normal form, but its βη-normal form is `λf.f`.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::beta_eta;
+/// use lambda_calculus::combinators::I;
+///
+/// let one = parse(&"λf.λx.f x", Classic).unwrap();
+///
+/// assert_eq!(beta(one.clone(), NOR, 0), one);
+/// assert_eq!(beta_eta(one, NOR, 0), I());
+/// ```
+pub fn beta_eta(mut term: Term, order: Order, limit: usize) -> Term {
+    let mut count = 0;
+
+    loop {
+        let remaining = if limit == 0 { 0 } else { limit - count };
+        let beta_count = term.reduce(order, remaining);
+        count += beta_count;
+
+        if limit != 0 && count == limit {
+            break;
+        }
+
+        let remaining = if limit == 0 { 0 } else { limit - count };
+        let eta_count = term.eta_reduce(remaining);
+        count += eta_count;
+
+        if eta_count == 0 || (limit != 0 && count == limit) {
+            break;
+        }
+    }
+
+    term
+}
+
+/// Performs a single step of
+/// [parallel β-reduction](https://en.wikipedia.org/wiki/Church%E2%80%93Rosser_theorem), i.e. the
+/// complete development of `term` (the Gross-Knuth step): all the redexes present in `term` are
+/// contracted at once, including the ones nested inside other redexes, while the redexes created
+/// by these contractions are left for the next step.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::parallel_step;
+///
+/// let term = parse(&"(λx.x x) ((λy.y) (λz.z))", Classic).unwrap();
+///
+/// // both redexes are contracted, but not the new one
+/// assert_eq!(parallel_step(term), parse(&"(λz.z) (λz.z)", Classic).unwrap());
+/// ```
+pub fn parallel_step(term: Term) -> Term {
+    match term {
+        Var(_) => term,
+        Abs(body) => abs(parallel_step(*body)),
+        App(boxed) => match *boxed {
+            (Abs(body), rhs) => {
+                let mut lhs = abs(parallel_step(*body));
+                lhs.apply(&parallel_step(rhs)).unwrap(); // safe; it's an abstraction
+                lhs
+            }
+            (lhs, rhs) => app(parallel_step(lhs), parallel_step(rhs)),
+        },
+    }
+}
+
+/// Returns the path from `term` to the redex that would be contracted next when reducing it with
+/// the specified evaluation `Order`, i.e. by `beta(term, order, 1)`, or `None` if `term` is
+/// already reduced as far as that `Order` goes. An empty path means that `term` itself is the
+/// next redex.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::{next_redex_path, Side::*};
+///
+/// let expr = parse(&"λa.(λb.b) ((λc.c) a)", Classic).unwrap();
+///
+/// assert_eq!(next_redex_path(&expr, NOR), Some(vec![Body]));
+/// assert_eq!(next_redex_path(&expr, APP), Some(vec![Body, Right]));
+/// assert_eq!(next_redex_path(&expr, CBN), None);
+/// ```
+pub fn next_redex_path(term: &Term, order: Order) -> Option<Vec<Side>> {
+    let mut path = Vec::new();
+
+    if find_redex(term, order, &mut path) {
+        path.reverse();
+        Some(path)
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if `term` is in the normal form associated with the specified evaluation
+/// `Order`, i.e. if reducing it with that `Order` would not change it: a full normal form for
+/// `NOR`, `HNO`, `APP` and `HAP`, a weak head normal form for `CBN`, a weak normal form for `CBV`
+/// and a head normal form for `HSP`. For the full normal form it is equivalent to
+/// `Term::is_normal_form`.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::is_normal;
+///
+/// let expr = parse(&"λa.(λb.b) a", Classic).unwrap();
+///
+/// assert!(is_normal(&expr, CBN));
+/// assert!(!is_normal(&expr, NOR));
+/// ```
+pub fn is_normal(term: &Term, order: Order) -> bool {
+    next_redex_path(term, order).is_none()
+}
+
+/// Returns `true` if `a` and `b` are β-equivalent, i.e. if they are equal or reduce to the same
+/// normal form using the specified evaluation `Order`, with an optional limit on the number of
+/// reductions of each of them (`0` means no limit).
+///
+/// β-equivalence is only semi-decidable: if the terms are not equal, but either of them has no
+/// normal form or doesn't reach it within the limit, the result is `false` even though they might
+/// be equivalent. A normalizing `Order` like `NOR` should be used; with the others only the
+/// normal forms they lead to (e.g. weak head normal forms for `CBN`) are compared.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::beta_eq;
+/// use lambda_calculus::combinators::{I, O};
+///
+/// assert!(beta_eq(&app(I(), I()), &I(), NOR, 0));
+/// assert!(!beta_eq(&app(I(), O()), &O(), NOR, 100)); // O has no normal form
+/// ```
+pub fn beta_eq(a: &Term, b: &Term, order: Order, limit: usize) -> bool {
+    if a == b {
+        return true;
+    }
+
+    match (
+        normalize_within(a, order, limit),
+        normalize_within(b, order, limit),
+    ) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Reduces `term` with each of the normalizing evaluation `Order`s (`NOR`, `HNO`, `APP` and `HAP`)
+/// with an optional limit on the number of reductions (`0` means no limit) and asserts that all
+/// the ones that reach a normal form within the limit reach the same one, as guaranteed by the
+/// [Church-Rosser theorem](https://en.wikipedia.org/wiki/Church%E2%80%93Rosser_theorem). It is
+/// meant for testing the reduction strategies and terms built with them.
+///
+/// The applicative orders don't terminate for terms that contain subterms without a normal form,
+/// e.g. recursive functions using `Y`, so a limit should be used for such terms.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::assert_confluent;
+/// use lambda_calculus::combinators::{I, K, O, S};
+///
+/// assert_confluent(&app!(S(), K(), K()), 0);
+/// assert_confluent(&app!(K(), I(), O()), 100); // only the normal orders reach a normal form
+/// ```
+///
+/// # Panics
+///
+/// Panics if two of the orders produce different normal forms.
+pub fn assert_confluent(term: &Term, limit: usize) {
+    let mut normal_forms = [NOR, HNO, APP, HAP]
+        .into_iter()
+        .filter_map(|order| normalize_within(term, order, limit).map(|normal| (order, normal)));
+
+    if let Some((first_order, first)) = normal_forms.next() {
+        for (order, normal) in normal_forms {
+            assert!(
+                normal == first,
+                "the {} and {} orders reduce {} to different normal forms: {} and {}",
+                first_order,
+                order,
+                term,
+                first,
+                normal
+            );
+        }
+    }
+}
+
+/// Reduces `term` with the given `Order`, returning `None` if it's not fully reduced within the
+/// limit.
+fn normalize_within(term: &Term, order: Order, limit: usize) -> Option<Term> {
+    let (term, count) = beta_counted(term.clone(), order, limit);
+
+    if limit == 0 || count < limit || is_normal(&term, order) {
+        Some(term)
+    } else {
+        None
+    }
+}
+
+/// Follows the traversal performed by the reduction with the given `Order` until it finds a redex;
+/// the path to it is pushed to `path` in reverse.
+fn find_redex(term: &Term, order: Order, path: &mut Vec<Side>) -> bool {
+    match *term {
+        Var(_) => false,
+        Abs(_) if order == CBN || order == CBV => false,
+        Abs(ref abstracted) => find_redex_in(Side::Body, abstracted, order, path),
+        App(ref boxed) => {
+            let (ref lhs, ref rhs) = **boxed;
+            let is_redex = lhs.unabs_ref().is_ok();
+
+            match order {
+                CBN => find_redex_in(Side::Left, lhs, CBN, path) || is_redex,
+                NOR | HNO => {
+                    let head_order = if order == NOR { CBN } else { HSP };
+
+                    find_redex_in(Side::Left, lhs, head_order, path)
+                        || is_redex
+                        || find_redex_in(Side::Left, lhs, order, path)
+                        || find_redex_in(Side::Right, rhs, order, path)
+                }
+                HSP => find_redex_in(Side::Left, lhs, HSP, path) || is_redex,
+                APP | CBV => {
+                    find_redex_in(Side::Left, lhs, order, path)
+                        || find_redex_in(Side::Right, rhs, order, path)
+                        || is_redex
+                }
+                HAP => {
+                    find_redex_in(Side::Left, lhs, CBV, path)
+                        || find_redex_in(Side::Right, rhs, HAP, path)
+                        || is_redex
+                        || find_redex_in(Side::Left, lhs, HAP, path)
+                }
+            }
+        }
+    }
+}
+
+fn find_redex_in(side: Side, term: &Term, order: Order, path: &mut Vec<Side>) -> bool {
+    let found = find_redex(term, order, path);
+
+    if found {
+        path.push(side);
+    }
+
+    found
+}
+
 impl Term {
     /// Applies a `Term` to `self` via substitution and variable update.
     ///
@@ -58,9 +570,9 @@ impl Term {
     /// ```
     /// use lambda_calculus::*;
     ///
-    /// let mut term1  = parse(&"λλ42(λ13)", DeBruijn).unwrap();
-    /// let term2      = parse(&"λ51", DeBruijn).unwrap();
-    /// let result     = parse(&"λ3(λ61)(λ1(λ71))", DeBruijn).unwrap();
+    /// let mut term1  = parse(&"λλ4 2(λ1 3)", DeBruijn).unwrap();
+    /// let term2      = parse(&"λ5 1", DeBruijn).unwrap();
+    /// let result     = parse(&"λ3(λ6 1)(λ1(λ7 1))", DeBruijn).unwrap();
     ///
     /// term1.apply(&term2);
     ///
@@ -85,7 +597,7 @@ impl Term {
             Var(i) => match (*i).cmp(&depth) {
                 cmp::Ordering::Equal => {
                     *self = rhs.to_owned(); // substitute a top-level variable from lhs with rhs
-                    self.update_free_variables(depth - 1, 0); // update indices of free variables from rhs
+                    self.shift(depth as isize - 1, 0); // update indices of free variables from rhs
                 }
                 cmp::Ordering::Greater => {
                     *self = Var(*i - 1); // decrement a free variable's index
@@ -101,18 +613,80 @@ impl Term {
         }
     }
 
-    fn update_free_variables(&mut self, added_depth: usize, own_depth: usize) {
+    /// Adds `by` to the De Bruijn indices of the variables that are free in `self` when it is
+    /// placed under `cutoff` additional abstractions, i.e. the ones greater than `cutoff` plus the
+    /// number of abstractions enclosing them within `self`. With a `cutoff` of `0` it affects all
+    /// the free variables of `self`; a positive `by` is needed when placing a term under `by` new
+    /// abstractions, and a negative one when removing abstractions that don't bind any of its
+    /// variables.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let mut term = abs(app!(Var(1), Var(2), Var(3))); // λ 1 2 3
+    ///
+    /// term.shift(2, 0); // the bound variable is not affected
+    /// assert_eq!(term, abs(app!(Var(1), Var(4), Var(5))));
+    ///
+    /// term.shift(-1, 3); // neither are the ones not greater than the cutoff
+    /// assert_eq!(term, abs(app!(Var(1), Var(4), Var(4))));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index would be decreased below `1`.
+    pub fn shift(&mut self, by: isize, cutoff: usize) {
         match self {
             Var(ref mut i) => {
-                if *i > own_depth {
-                    *i += added_depth
+                if *i > cutoff {
+                    *i = i
+                        .checked_add_signed(by)
+                        .filter(|&i| i > 0)
+                        .expect("a De Bruijn index can't be shifted below 1")
+                }
+            }
+            Abs(ref mut abstracted) => abstracted.shift(by, cutoff + 1),
+            App(boxed) => {
+                let (ref mut lhs, ref mut rhs) = **boxed;
+                lhs.shift(by, cutoff);
+                rhs.shift(by, cutoff)
+            }
+        }
+    }
+
+    /// Replaces the free variable with the De Bruijn index `index` (as seen from the outside of
+    /// `self`) with `value`; the indices of the free variables of `value` are shifted accordingly
+    /// wherever it is placed under abstractions. The indices of the other free variables of `self`
+    /// remain unchanged, so contracting a redex `(λ M) N` corresponds to substituting `1` in `M`
+    /// with `N` shifted by `1` and then shifting `M` by `-1`.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let mut term = app(Var(1), abs(app(Var(1), Var(2)))); // 1 (λ 1 2)
+    ///
+    /// term.substitute(1, &Var(5));
+    /// assert_eq!(term, app(Var(5), abs(app(Var(1), Var(6)))));
+    /// ```
+    pub fn substitute(&mut self, index: usize, value: &Term) {
+        self.substitute_at(index, value, 0)
+    }
+
+    fn substitute_at(&mut self, index: usize, value: &Term, depth: usize) {
+        match self {
+            Var(i) => {
+                if *i == index + depth {
+                    *self = value.to_owned();
+                    self.shift(depth as isize, 0);
                 }
             }
-            Abs(ref mut abstracted) => abstracted.update_free_variables(added_depth, own_depth + 1),
+            Abs(ref mut abstracted) => abstracted.substitute_at(index, value, depth + 1),
             App(boxed) => {
                 let (ref mut lhs, ref mut rhs) = **boxed;
-                lhs.update_free_variables(added_depth, own_depth);
-                rhs.update_free_variables(added_depth, own_depth)
+                lhs.substitute_at(index, value, depth);
+                rhs.substitute_at(index, value, depth)
             }
         }
     }
@@ -134,7 +708,10 @@ impl Term {
     /// on the number of reductions (`0` means no limit) and returns the number of performed
     /// reductions.
     ///
-    /// # Example
+    /// The reduction is performed in place; with a limit of `1` it contracts exactly one redex
+    /// (the next one according to the `Order`), so the returned `0` means that no redex was found.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use lambda_calculus::*;
@@ -146,6 +723,17 @@ impl Term {
     ///
     /// assert_eq!(expression, reduced);
     /// ```
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let mut expression = parse(&"(λa.a) ((λa.a) (λa.a))", Classic).unwrap();
+    ///
+    /// assert_eq!(expression.reduce(NOR, 1), 1);
+    /// assert_eq!(expression, parse(&"(λa.a) (λa.a)", Classic).unwrap());
+    /// assert_eq!(expression.reduce(NOR, 1), 1);
+    /// assert_eq!(expression, parse(&"λa.a", Classic).unwrap());
+    /// assert_eq!(expression.reduce(NOR, 1), 0);
+    /// ```
     pub fn reduce(&mut self, order: Order, limit: usize) -> usize {
         let mut count = 0;
 
@@ -162,6 +750,122 @@ impl Term {
         count
     }
 
+    /// Returns the number of β-redexes, i.e. applications of abstractions, in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lambda_calculus::*;
+    /// use lambda_calculus::combinators::{I, O};
+    ///
+    /// assert_eq!(app(I(), I()).redex_count(), 1);
+    /// assert_eq!(app(I(), O()).redex_count(), 2);
+    /// assert_eq!(I().redex_count(), 0);
+    /// ```
+    pub fn redex_count(&self) -> usize {
+        self.subterms().filter(|t| t.is_reducible(0, 0)).count()
+    }
+
+    /// Returns `true` if `self` is in β-normal form, i.e. if it contains no β-redexes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lambda_calculus::*;
+    /// use lambda_calculus::combinators::{I, K};
+    ///
+    /// assert!(K().is_normal_form());
+    /// assert!(!app(K(), I()).is_normal_form());
+    /// ```
+    pub fn is_normal_form(&self) -> bool {
+        !self.subterms().any(|t| t.is_reducible(0, 0))
+    }
+
+    /// Performs η-reduction on a `Term`, i.e. replaces its subterms of the form `λx.f x` where `x`
+    /// does not occur freely in `f` with `f`, with an optional limit on the number of reductions
+    /// (`0` means no limit) and returns the number of performed reductions. The reductions are
+    /// performed from the innermost subterms outwards, so a single pass produces the η-normal
+    /// form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let mut expression = abs(app(Var(2), Var(1))); // λ 2 1
+    /// let mut irreducible = abs(app(Var(1), Var(1))); // λ 1 1
+    ///
+    /// assert_eq!(expression.eta_reduce(0), 1);
+    /// assert_eq!(expression, Var(1));
+    ///
+    /// assert_eq!(irreducible.eta_reduce(0), 0);
+    /// assert_eq!(irreducible, abs(app(Var(1), Var(1))));
+    /// ```
+    pub fn eta_reduce(&mut self, limit: usize) -> usize {
+        let mut count = 0;
+        self.eta_inner(limit, &mut count);
+        count
+    }
+
+    fn eta_inner(&mut self, limit: usize, count: &mut usize) {
+        if limit != 0 && *count == limit {
+            return;
+        }
+
+        match *self {
+            Var(_) => (),
+            Abs(ref mut abstracted) => {
+                abstracted.eta_inner(limit, count);
+
+                if limit != 0 && *count == limit {
+                    return;
+                }
+
+                if let App(ref boxed) = **abstracted {
+                    let (ref lhs, ref rhs) = **boxed;
+                    if *rhs == Var(1) && !lhs.has_free_variable(1) {
+                        let reduced = mem::replace(self, Var(0)); // replace self with a dummy
+                        let (mut lhs, _) = reduced.unabs().unwrap().unapp().unwrap(); // safe; checked above
+                        lhs.shift(-1, 0);
+                        *self = lhs;
+                        *count += 1;
+                    }
+                }
+            }
+            App(ref mut boxed) => {
+                let (ref mut lhs, ref mut rhs) = **boxed;
+                lhs.eta_inner(limit, count);
+                rhs.eta_inner(limit, count);
+            }
+        }
+    }
+
+    /// Returns the number of occurrences of the variable with the given index, counted from the
+    /// outside of `self`.
+    fn occurrences(&self, index: usize) -> usize {
+        match *self {
+            Var(i) => usize::from(i == index),
+            Abs(ref abstracted) => abstracted.occurrences(index + 1),
+            App(ref boxed) => {
+                let (ref lhs, ref rhs) = **boxed;
+                lhs.occurrences(index) + rhs.occurrences(index)
+            }
+        }
+    }
+
+    /// Returns `true` if the variable with the given index, counted from the outside of `self`,
+    /// occurs in it.
+    fn has_free_variable(&self, index: usize) -> bool {
+        match *self {
+            Var(i) => i == index,
+            Abs(ref abstracted) => abstracted.has_free_variable(index + 1),
+            App(ref boxed) => {
+                let (ref lhs, ref rhs) = **boxed;
+                lhs.has_free_variable(index) || rhs.has_free_variable(index)
+            }
+        }
+    }
+
     fn beta_cbn(&mut self, limit: usize, count: &mut usize) {
         if limit != 0 && *count == limit {
             return;
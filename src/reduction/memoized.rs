@@ -0,0 +1,143 @@
+//! A normal-order reducer caching the results of reducing closed subterms, used by
+//! `normalize_memoized`
+
+use super::shared::{share, shared_abs, shared_app, substitute, unshare, Node};
+use crate::term::Term;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+struct Reducer {
+    /// the normal forms of closed applications
+    normal_forms: HashMap<Term, Rc<Node>>,
+    /// the weak head normal forms of closed applications
+    head_forms: HashMap<Term, Rc<Node>>,
+    limit: usize,
+    count: usize,
+}
+
+impl Reducer {
+    fn exhausted(&self) -> bool {
+        self.limit != 0 && self.count == self.limit
+    }
+
+    /// Contracts the redex formed by applying `body` (wrapped in an abstraction) to `arg`.
+    fn contract(&mut self, body: &Rc<Node>, arg: &Rc<Node>) -> Rc<Node> {
+        self.count += 1;
+        substitute(body, 0, arg)
+    }
+
+    /// Reduces the leftmost outermost redexes that are not inside abstractions.
+    fn whnf(&mut self, node: &Rc<Node>) -> Rc<Node> {
+        let Node::App(ref lhs, ref rhs, max_free) = **node else {
+            return Rc::clone(node);
+        };
+
+        // the closedness of every node is computed once, when it is built
+        let key = if max_free == 0 {
+            let key = unshare(node);
+            if let Some(reduced) = self.head_forms.get(&key) {
+                return Rc::clone(reduced);
+            }
+            Some(key)
+        } else {
+            None
+        };
+
+        let lhs = self.whnf(lhs);
+
+        let reduced = match *lhs {
+            Node::Abs(ref body, _) if !self.exhausted() => {
+                let reduced = self.contract(body, rhs);
+                self.whnf(&reduced)
+            }
+            _ => shared_app(lhs, Rc::clone(rhs)),
+        };
+
+        // a term reduced only partially due to the limit must not be reused
+        if let Some(key) = key.filter(|_| !self.exhausted()) {
+            self.head_forms.insert(key, Rc::clone(&reduced));
+        }
+
+        reduced
+    }
+
+    /// Reduces the leftmost outermost redexes.
+    fn normalize(&mut self, node: &Rc<Node>) -> Rc<Node> {
+        if self.exhausted() {
+            return Rc::clone(node);
+        }
+
+        match **node {
+            Node::Var(_) => Rc::clone(node),
+            Node::Abs(ref body, _) => shared_abs(self.normalize(body)),
+            Node::App(ref lhs, ref rhs, max_free) => {
+                let key = if max_free == 0 {
+                    let key = unshare(node);
+                    if let Some(normal) = self.normal_forms.get(&key) {
+                        return Rc::clone(normal);
+                    }
+                    Some(key)
+                } else {
+                    None
+                };
+
+                let lhs = self.whnf(lhs);
+
+                let normal = match *lhs {
+                    Node::Abs(ref body, _) if !self.exhausted() => {
+                        let reduced = self.contract(body, rhs);
+                        self.normalize(&reduced)
+                    }
+                    _ => {
+                        let lhs = self.normalize(&lhs);
+                        let rhs = self.normalize(rhs);
+                        shared_app(lhs, rhs)
+                    }
+                };
+
+                if let Some(key) = key.filter(|_| !self.exhausted()) {
+                    self.normal_forms.insert(key, Rc::clone(&normal));
+                }
+
+                normal
+            }
+        }
+    }
+}
+
+/// Performs β-reduction on a `Term` in the normal order (like `beta(term, NOR, limit)`) with an
+/// optional limit on the number of reductions (`0` means no limit) and returns the reduced
+/// `Term`.
+///
+/// The results of reducing closed applications (their weak head normal and normal forms) are
+/// cached for the duration of the reduction, so that the copies of an argument duplicated by the
+/// normal order are only reduced once; this greatly speeds up recursive functions that evaluate
+/// the same subproblems multiple times, like a naive Fibonacci function, but the cost of hashing
+/// the subterms makes it slower than `beta` for terms that don't repeat any work. Since the
+/// reductions of cached subterms are not performed again, a limit may be reached later than
+/// with `beta`. Like in `normalize_shared`, the `Term` is converted to a representation sharing
+/// its subterms for the duration of the reduction; it also tracks which subterms are closed, so
+/// that they don't have to be scanned again whenever they are considered for caching.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::normalize_memoized;
+///
+/// let expr = parse(&"(λa.a a) ((λa.λb.λc.b (a b c)) (λa.λb.b))", Classic).unwrap();
+///
+/// assert_eq!(normalize_memoized(expr.clone(), 0), beta(expr, NOR, 0));
+/// ```
+pub fn normalize_memoized(term: Term, limit: usize) -> Term {
+    let mut reducer = Reducer {
+        normal_forms: HashMap::new(),
+        head_forms: HashMap::new(),
+        limit,
+        count: 0,
+    };
+
+    let reduced = reducer.normalize(&share(&term));
+
+    unshare(&reduced)
+}
@@ -0,0 +1,179 @@
+//! A representation of lambda terms sharing their subterms, used by `normalize_shared`
+
+use crate::term::Term::{self, *};
+use crate::term::{abs, app};
+use std::rc::Rc;
+
+/// A lambda term with reference-counted subterms, caching the greatest De Bruijn index of its
+/// free variables (`0` for closed terms).
+pub(super) enum Node {
+    Var(usize),
+    Abs(Rc<Node>, usize),
+    App(Rc<Node>, Rc<Node>, usize),
+}
+
+impl Node {
+    pub(super) fn max_free(&self) -> usize {
+        match *self {
+            Node::Var(i) => i,
+            Node::Abs(_, max_free) | Node::App(_, _, max_free) => max_free,
+        }
+    }
+}
+
+fn var(i: usize) -> Rc<Node> {
+    Rc::new(Node::Var(i))
+}
+
+pub(super) fn shared_abs(body: Rc<Node>) -> Rc<Node> {
+    let max_free = body.max_free().saturating_sub(1);
+    Rc::new(Node::Abs(body, max_free))
+}
+
+pub(super) fn shared_app(lhs: Rc<Node>, rhs: Rc<Node>) -> Rc<Node> {
+    let max_free = lhs.max_free().max(rhs.max_free());
+    Rc::new(Node::App(lhs, rhs, max_free))
+}
+
+pub(super) fn share(term: &Term) -> Rc<Node> {
+    match *term {
+        Var(i) => var(i),
+        Abs(ref body) => shared_abs(share(body)),
+        App(ref boxed) => shared_app(share(&boxed.0), share(&boxed.1)),
+    }
+}
+
+pub(super) fn unshare(node: &Node) -> Term {
+    match *node {
+        Node::Var(i) => Var(i),
+        Node::Abs(ref body, _) => abs(unshare(body)),
+        Node::App(ref lhs, ref rhs, _) => app(unshare(lhs), unshare(rhs)),
+    }
+}
+
+/// Increases the indices of the free variables greater than `cutoff` by `by`; the subterms
+/// without such variables are shared instead of copied.
+fn shift(node: &Rc<Node>, by: usize, cutoff: usize) -> Rc<Node> {
+    if by == 0 || node.max_free() <= cutoff {
+        return Rc::clone(node);
+    }
+
+    match **node {
+        Node::Var(i) => var(i + by),
+        Node::Abs(ref body, _) => shared_abs(shift(body, by, cutoff + 1)),
+        Node::App(ref lhs, ref rhs, _) => {
+            shared_app(shift(lhs, by, cutoff), shift(rhs, by, cutoff))
+        }
+    }
+}
+
+/// Substitutes the variable bound by the abstraction `depth` levels above `node` with `arg`,
+/// decrementing the indices of the variables bound outside of it; the subterms without such
+/// variables are shared instead of copied.
+pub(super) fn substitute(node: &Rc<Node>, depth: usize, arg: &Rc<Node>) -> Rc<Node> {
+    if node.max_free() <= depth {
+        return Rc::clone(node);
+    }
+
+    match **node {
+        Node::Var(i) => {
+            if i == depth + 1 {
+                shift(arg, depth, 0)
+            } else {
+                var(i - 1) // i > depth + 1
+            }
+        }
+        Node::Abs(ref body, _) => shared_abs(substitute(body, depth + 1, arg)),
+        Node::App(ref lhs, ref rhs, _) => {
+            shared_app(substitute(lhs, depth, arg), substitute(rhs, depth, arg))
+        }
+    }
+}
+
+struct Reducer {
+    limit: usize,
+    count: usize,
+}
+
+impl Reducer {
+    fn exhausted(&self) -> bool {
+        self.limit != 0 && self.count == self.limit
+    }
+
+    /// Contracts the redex formed by applying `body` (wrapped in an abstraction) to `arg`.
+    fn contract(&mut self, body: &Rc<Node>, arg: &Rc<Node>) -> Rc<Node> {
+        self.count += 1;
+        substitute(body, 0, arg)
+    }
+
+    /// Reduces the leftmost outermost redexes that are not inside abstractions.
+    fn whnf(&mut self, node: &Rc<Node>) -> Rc<Node> {
+        match **node {
+            Node::App(ref lhs, ref rhs, _) => {
+                let lhs = self.whnf(lhs);
+
+                match *lhs {
+                    Node::Abs(ref body, _) if !self.exhausted() => {
+                        let reduced = self.contract(body, rhs);
+                        self.whnf(&reduced)
+                    }
+                    _ => shared_app(lhs, Rc::clone(rhs)),
+                }
+            }
+            _ => Rc::clone(node),
+        }
+    }
+
+    /// Reduces the leftmost outermost redexes.
+    fn normalize(&mut self, node: &Rc<Node>) -> Rc<Node> {
+        if self.exhausted() {
+            return Rc::clone(node);
+        }
+
+        match **node {
+            Node::Var(_) => Rc::clone(node),
+            Node::Abs(ref body, _) => shared_abs(self.normalize(body)),
+            Node::App(ref lhs, ref rhs, _) => {
+                let lhs = self.whnf(lhs);
+
+                match *lhs {
+                    Node::Abs(ref body, _) if !self.exhausted() => {
+                        let reduced = self.contract(body, rhs);
+                        self.normalize(&reduced)
+                    }
+                    _ => {
+                        let lhs = self.normalize(&lhs);
+                        let rhs = self.normalize(rhs);
+                        shared_app(lhs, rhs)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Performs β-reduction on a `Term` in the normal order (like `beta(term, NOR, limit)`) with an
+/// optional limit on the number of reductions (`0` means no limit) and returns the reduced
+/// `Term`.
+///
+/// The `Term` is converted to a representation sharing its subterms for the duration of the
+/// reduction, so that substitutions copy only the parts of the term that actually change instead
+/// of whole subtrees; this makes it considerably faster for terms whose reduction involves many
+/// copies of large arguments.
+///
+/// # Example
+///
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::reduction::normalize_shared;
+///
+/// let expr = parse(&"(λa.λb.λc.b (a b c)) (λa.λb.b)", Classic).unwrap();
+///
+/// assert_eq!(normalize_shared(expr.clone(), 0), beta(expr, NOR, 0));
+/// ```
+pub fn normalize_shared(term: Term, limit: usize) -> Term {
+    let mut reducer = Reducer { limit, count: 0 };
+    let reduced = reducer.normalize(&share(&term));
+
+    unshare(&reduced)
+}
@@ -3,9 +3,14 @@
 pub use self::Notation::*;
 pub use self::Term::*;
 use self::TermError::*;
+use crate::parser::{parse, ParseError};
 use std::borrow::Cow;
-use std::char::from_u32;
 use std::fmt;
+use std::ops::Mul;
+use std::str::FromStr;
+
+#[cfg(feature = "rand")]
+pub mod random;
 
 /// The character used to display lambda abstractions (a backslash).
 #[cfg(feature = "backslash_lambda")]
@@ -28,7 +33,7 @@ pub const UD: Term = Var(0);
 /// use lambda_calculus::combinators::S;
 ///
 /// assert_eq!(&format!(  "{}", S()), "λa.λb.λc.a c (b c)"); // Classic notation
-/// assert_eq!(&format!("{:?}", S()), "λλλ31(21)");          // DeBruijn index notation
+/// assert_eq!(&format!("{:?}", S()), "λλλ3 1(2 1)");        // DeBruijn index notation
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Notation {
@@ -36,11 +41,22 @@ pub enum Notation {
     Classic,
     /// De Bruijn indices; used by `fmt::Debug`
     DeBruijn,
+    /// classic lambda calculus notation with a backslash (`\`) in place of the lambda, so that it
+    /// consists only of ASCII characters; it is parsed just like `Classic`
+    Ascii,
 }
 
 /// A lambda term that is either a variable with a De Bruijn index, an abstraction over a term or
 /// an applicaction of one term to another.
-#[derive(PartialEq, Clone, Hash, Eq)]
+///
+/// Since the variables are represented with De Bruijn indices, α-equivalent terms are equal and
+/// have the same hash; the ordering is structural (`Var` < `Abs` < `App`), so terms can be used
+/// as keys of both hashed and ordered collections.
+///
+/// With the `serde` feature enabled it can also be serialized and deserialized; the variants are
+/// externally tagged, e.g. `λ1` is represented as `{"Abs":{"Var":1}}` in JSON.
+#[derive(PartialEq, Clone, Hash, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Term {
     /// a variable
     Var(usize),
@@ -380,6 +396,395 @@ impl Term {
         }
         true
     }
+
+    /// Returns the De Bruijn indices of the free variables of `self`, as seen from its outside
+    /// (i.e. with the indices of variables under abstractions decreased by the number of those
+    /// abstractions), in ascending order and without duplicates.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let term = abs(app!(Var(1), Var(3), abs(Var(4)), Var(2))); // λ 1 3 (λ 4) 2
+    ///
+    /// assert_eq!(term.free_variables(), vec![1, 2]);
+    /// assert_eq!(abs(Var(1)).free_variables(), vec![]);
+    /// ```
+    pub fn free_variables(&self) -> Vec<usize> {
+        let mut free = Vec::new();
+        let mut stack = vec![(0usize, self)];
+
+        while let Some((depth, term)) = stack.pop() {
+            match term {
+                Var(i) => {
+                    if *i > depth {
+                        free.push(*i - depth);
+                    }
+                }
+                Abs(ref t) => stack.push((depth + 1, t)),
+                App(boxed) => {
+                    let (ref lhs, ref rhs) = **boxed;
+                    stack.push((depth, rhs));
+                    stack.push((depth, lhs));
+                }
+            }
+        }
+
+        free.sort_unstable();
+        free.dedup();
+        free
+    }
+
+    /// Returns `true` if `self` has no free variables. It is equivalent to
+    /// `self.free_variables().is_empty()`, but doesn't allocate the list of variables.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// assert!(abs!(2, app(Var(2), Var(1))).is_closed()); // λ λ 2 1
+    /// assert!(!abs(Var(2)).is_closed()); // λ 2
+    /// ```
+    pub fn is_closed(&self) -> bool {
+        self.is_supercombinator()
+    }
+
+    /// Returns `true` if `self` and `other` are alpha-equivalent, i.e. if they only differ in the
+    /// names of their bound variables.
+    ///
+    /// Since terms are represented with De Bruijn indices, bound variables carry no names and
+    /// this is the same as `self == other`; it is provided for those used to named
+    /// representations. Free variables are compared in the frame outside of the whole term, so
+    /// `λ 2` is only alpha-equivalent to another `λ 2` and not to `λ 3`, and terms built by
+    /// [`shift`](Term::shift)ing their free variables back and forth remain equivalent.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let k1 = parse("λx.λy.x", Classic).unwrap();
+    /// let k2 = parse("λa.λb.a", Classic).unwrap();
+    ///
+    /// assert!(k1.alpha_eq(&k2));
+    /// assert!(k1.alpha_eq(&abs!(2, Var(2))));
+    /// assert!(!k1.alpha_eq(&abs!(2, Var(1))));
+    /// ```
+    pub fn alpha_eq(&self, other: &Term) -> bool {
+        self == other
+    }
+
+    /// Returns `true` if `self` and `other` are equal up to
+    /// [η-conversion](https://en.wikipedia.org/wiki/Lambda_calculus#%CE%B7-reduction), i.e. if
+    /// one can be obtained from the other by contracting or expanding subterms of the form
+    /// `λx.f x` where `x` does not occur freely in `f`. No β-reduction is performed, so this is
+    /// weaker than [`beta_eq`](crate::reduction::beta_eq), but it is always decidable: η-reduction
+    /// terminates and the terms are compared by their (unique) η-normal forms, which only take a
+    /// single pass to compute.
+    ///
+    /// In De Bruijn notation the condition reads `λ f 1` where `f` does not contain the index `1`
+    /// (counting from its own outside), and contracting it decreases the free variables of `f` by
+    /// one. As with [`alpha_eq`](Term::alpha_eq), free variables are compared in the frame
+    /// outside of the whole term, so `λ 2 1` is η-equivalent to `1` (the variable `2` inside the
+    /// abstraction is the `1` outside of it), while `λ 1 1` is not η-equivalent to `1`.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    /// use lambda_calculus::combinators::{I, K};
+    ///
+    /// assert!(abs(app(K(), Var(1))).eta_eq(&K())); // λ K 1
+    /// assert!(abs(app(Var(2), Var(1))).eta_eq(&Var(1))); // λ 2 1
+    /// assert!(abs!(2, app!(Var(3), Var(2), Var(1))).eta_eq(&Var(1))); // λ λ 3 2 1
+    /// assert!(!abs(app(Var(1), Var(1))).eta_eq(&Var(1))); // λ 1 1
+    /// assert!(!app(I(), Var(1)).eta_eq(&Var(1))); // β-equal only
+    /// ```
+    pub fn eta_eq(&self, other: &Term) -> bool {
+        if self == other {
+            return true;
+        }
+
+        let (mut lhs, mut rhs) = (self.clone(), other.clone());
+        lhs.eta_reduce(0);
+        rhs.eta_reduce(0);
+
+        lhs == rhs
+    }
+
+    /// Returns the size of `self`, i.e. the number of its variables, abstractions and
+    /// applications.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    /// use lambda_calculus::combinators::{I, Y};
+    ///
+    /// assert_eq!(I().size(), 2);
+    /// assert_eq!(Y().size(), 14);
+    /// ```
+    pub fn size(&self) -> usize {
+        let mut count = 0;
+        let mut stack = vec![self];
+
+        while let Some(term) = stack.pop() {
+            count += 1;
+            match term {
+                Var(_) => (),
+                Abs(ref t) => stack.push(t),
+                App(boxed) => {
+                    let (ref lhs, ref rhs) = **boxed;
+                    stack.push(lhs);
+                    stack.push(rhs);
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Returns the depth of `self`, i.e. the number of variables, abstractions and applications
+    /// on the longest path from its root to one of its variables.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    /// use lambda_calculus::combinators::{I, Y};
+    ///
+    /// assert_eq!(I().depth(), 2);
+    /// assert_eq!(Y().depth(), 6);
+    /// ```
+    pub fn depth(&self) -> usize {
+        let mut max = 0;
+        let mut stack = vec![(1usize, self)];
+
+        while let Some((depth, term)) = stack.pop() {
+            match term {
+                Var(_) => max = max.max(depth),
+                Abs(ref t) => stack.push((depth + 1, t)),
+                App(boxed) => {
+                    let (ref lhs, ref rhs) = **boxed;
+                    stack.push((depth + 1, lhs));
+                    stack.push((depth + 1, rhs));
+                }
+            }
+        }
+
+        max
+    }
+
+    /// Returns an iterator over all the subterms of `self`, including `self`, in pre-order, i.e.
+    /// every abstraction and application is followed by its subterms and the left-hand side of an
+    /// application is visited before its right-hand side.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let term = abs(app(Var(1), Var(2))); // λ 1 2
+    ///
+    /// assert_eq!(
+    ///     term.subterms().collect::<Vec<_>>(),
+    ///     vec![&term, &app(Var(1), Var(2)), &Var(1), &Var(2)]
+    /// );
+    /// ```
+    pub fn subterms(&self) -> impl Iterator<Item = &Term> {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || {
+            let term = stack.pop()?;
+
+            match term {
+                Var(_) => (),
+                Abs(ref t) => stack.push(t),
+                App(boxed) => {
+                    let (ref lhs, ref rhs) = **boxed;
+                    stack.push(rhs);
+                    stack.push(lhs);
+                }
+            }
+
+            Some(term)
+        })
+    }
+
+    /// Applies `f` to all the subterms of `self`, including `self`, in the same order as
+    /// `subterms()`; since a subterm is visited before its own subterms, the ones `f` replaces it
+    /// with are visited next, so `f` can rewrite the `Term` in place. A mutable iterator is not
+    /// provided, because it would have to hand out overlapping references.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let mut term = abs(app(Var(1), Var(2))); // λ 1 2
+    ///
+    /// term.for_each_subterm_mut(|t| {
+    ///     if let Var(ref mut i) = *t {
+    ///         *i += 1
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(term, abs(app(Var(2), Var(3))));
+    /// ```
+    pub fn for_each_subterm_mut<F: FnMut(&mut Term)>(&mut self, mut f: F) {
+        let mut stack = vec![self];
+
+        while let Some(term) = stack.pop() {
+            f(term);
+
+            match term {
+                Var(_) => (),
+                Abs(t) => stack.push(t),
+                App(boxed) => {
+                    let (ref mut lhs, ref mut rhs) = **boxed;
+                    stack.push(rhs);
+                    stack.push(lhs);
+                }
+            }
+        }
+    }
+
+    /// Applies `self` to the given arguments in order, producing left-associated applications
+    /// like the `app!` macro does, but with an argument list known only at runtime.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let args = [Var(2), Var(3), Var(4)];
+    ///
+    /// assert_eq!(Var(1).applied_to(&args), app!(Var(1), Var(2), Var(3), Var(4)));
+    /// assert_eq!(Var(1).applied_to(&[]), Var(1));
+    /// ```
+    pub fn applied_to(self, args: &[Term]) -> Term {
+        args.iter().cloned().fold(self, app)
+    }
+
+    /// Returns a [GraphViz](https://graphviz.org/) `digraph` of the syntax tree of `self` in the
+    /// DOT language; the nodes are numbered in pre-order and labeled with the lambda for an
+    /// abstraction, `@` for an application and the De Bruijn index for a variable. The output
+    /// can be rendered with e.g. `dot -Tpng`.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    ///
+    /// let dot = abs(app(Var(1), Var(1))).to_dot(); // λ 1 1
+    ///
+    /// assert!(dot.starts_with("digraph term {"));
+    /// assert!(dot.contains("1 [label=\"@\"];"));
+    /// assert!(dot.contains("1 -> 2;"));
+    /// assert!(dot.contains("1 -> 3;"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut output = String::from("digraph term {\n");
+        let mut stack = vec![(None, self)];
+        let mut id = 0;
+
+        while let Some((parent, term)) = stack.pop() {
+            let label = match term {
+                Var(i) => i.to_string(),
+                Abs(ref t) => {
+                    stack.push((Some(id), t));
+                    LAMBDA.to_string()
+                }
+                App(boxed) => {
+                    let (ref lhs, ref rhs) = **boxed;
+                    stack.push((Some(id), rhs));
+                    stack.push((Some(id), lhs));
+                    "@".into()
+                }
+            };
+
+            output.push_str(&format!("    {} [label=\"{}\"];\n", id, label));
+            if let Some(parent) = parent {
+                output.push_str(&format!("    {} -> {};\n", parent, id));
+            }
+            id += 1;
+        }
+
+        output.push('}');
+        output
+    }
+
+    /// Returns the textual representation of `self` in the given `Notation`; it is equivalent to
+    /// `format!("{}", self)` for `Classic`, `format!("{:?}", self)` for `DeBruijn` and
+    /// `self.to_ascii_string()` for `Ascii`. In the `Classic` and `Ascii` notations the variables
+    /// are named after the abstractions that bind them (`a`, `b`, ..., `z`, `aa`, `ab` etc.) and
+    /// the free variables get names following the ones of the most deeply nested abstraction, so
+    /// that each of them has a single name that can't be captured.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    /// use lambda_calculus::combinators::K;
+    ///
+    /// assert_eq!(K().to_string_as(Classic), "λa.λb.a");
+    /// assert_eq!(K().to_string_as(DeBruijn), "λλ2");
+    /// assert_eq!(K().to_string_as(Ascii), "\\a.\\b.a");
+    /// ```
+    pub fn to_string_as(&self, notation: Notation) -> String {
+        match notation {
+            Classic => show_precedence_cla(self, 0, 0, binder_depth(self)),
+            DeBruijn => show_precedence_dbr(self, 0),
+            Ascii => self.to_ascii_string(),
+        }
+    }
+
+    /// Returns the textual representation of `self` in the `Classic` notation, with a backslash
+    /// (`\`) in place of the lambda regardless of the `backslash_lambda` feature, so that it
+    /// consists only of ASCII characters; it can be parsed back with `parse`.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::*;
+    /// use lambda_calculus::combinators::S;
+    ///
+    /// assert_eq!(S().to_ascii_string(), "\\a.\\b.\\c.a c (b c)");
+    /// assert_eq!(parse(&S().to_ascii_string(), Classic), Ok(S()));
+    /// ```
+    pub fn to_ascii_string(&self) -> String {
+        self.to_string_as(Classic).replace(LAMBDA, "\\")
+    }
+
+    /// Returns the [binary lambda calculus](https://tromp.github.io/cl/Binary_lambda_calculus.html)
+    /// encoding of `self`: `00` for an abstraction, `01` for an application and `1ⁿ0` for a
+    /// variable with the De Bruijn index `n`. Since the indices start with 1, `Var(1)` is
+    /// encoded as `10`, `Var(2)` as `110` etc.; the `UD` term has no meaningful encoding and is
+    /// encoded as `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use lambda_calculus::combinators::{I, K, S};
+    ///
+    /// assert_eq!(I().to_blc(), "0010");
+    /// assert_eq!(K().to_blc(), "0000110");
+    /// assert_eq!(S().to_blc(), "00000001011110100111010");
+    /// ```
+    pub fn to_blc(&self) -> String {
+        let mut output = String::new();
+        let mut stack = vec![self];
+
+        while let Some(term) = stack.pop() {
+            match term {
+                Var(i) => {
+                    output.extend((0..*i).map(|_| '1'));
+                    output.push('0');
+                }
+                Abs(ref t) => {
+                    output.push_str("00");
+                    stack.push(t);
+                }
+                App(boxed) => {
+                    let (ref lhs, ref rhs) = **boxed;
+                    output.push_str("01");
+                    stack.push(rhs);
+                    stack.push(lhs);
+                }
+            }
+        }
+
+        output
+    }
 }
 
 /// Wraps a `Term` in an `Abs`traction. Consumes its argument.
@@ -394,6 +799,22 @@ pub fn abs(term: Term) -> Term {
     Abs(Box::new(term))
 }
 
+/// Wraps a `Term` in `n` `Abs`tractions. Consumes its argument.
+///
+/// It is the function counterpart of the `abs!` macro, handy when the number of abstractions is
+/// computed at runtime.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+///
+/// assert_eq!(abs_n(3, Var(1)), abs(abs(abs(Var(1)))));
+/// assert_eq!(abs_n(0, Var(1)), Var(1));
+/// ```
+pub fn abs_n(n: usize, term: Term) -> Term {
+    (0..n).fold(term, |term, _| abs(term))
+}
+
 /// Produces an `App`lication of two given `Term`s without any reduction, consuming them in the
 /// process.
 ///
@@ -407,33 +828,50 @@ pub fn app(lhs: Term, rhs: Term) -> Term {
     App(Box::new((lhs, rhs)))
 }
 
+/// Displays the `Term` in the `Classic` notation; with the alternate flag (`{:#}`) only the
+/// parentheses that are necessary to parse it back are used, i.e. an application's argument that
+/// is an abstraction is not parenthesized if nothing follows it.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+///
+/// let term = abs(app(Var(1), abs(Var(2)))); // λ 1 (λ 2)
+///
+/// assert_eq!(format!("{}", term), "λa.a (λb.a)");
+/// assert_eq!(format!("{:#}", term), "λa.a λb.a");
+/// ```
 impl fmt::Display for Term {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", show_precedence_cla(self, 0, 0))
+        if f.alternate() {
+            let binders = binder_depth(self);
+            write!(
+                f,
+                "{}",
+                show_minimal(self, Classic, Position::Top, true, 0, binders)
+            )
+        } else {
+            write!(f, "{}", show_precedence_cla(self, 0, 0, binder_depth(self)))
+        }
     }
 }
 
-fn show_precedence_cla(term: &Term, context_precedence: usize, depth: u32) -> String {
+fn show_precedence_cla(
+    term: &Term,
+    context_precedence: usize,
+    depth: usize,
+    binders: usize,
+) -> String {
     match term {
         Var(0) => "undefined".to_owned(),
-        Var(i) => {
-            if depth >= *i as u32 {
-                from_u32(depth + 97 - *i as u32)
-                    .expect("error while printing term")
-                    .to_string()
-            } else {
-                from_u32(96 + *i as u32)
-                    .expect("error while printing term")
-                    .to_string()
-            }
-        }
+        Var(i) => classic_name(*i, depth, binders),
         Abs(ref t) => {
             let ret = {
                 format!(
                     "{}{}.{}",
                     LAMBDA,
-                    from_u32(depth + 97).expect("error while printing term"),
-                    show_precedence_cla(t, 0, depth + 1)
+                    variable_name(depth),
+                    show_precedence_cla(t, 0, depth + 1, binders)
                 )
             };
             parenthesize_if(&ret, context_precedence > 1).into()
@@ -442,17 +880,83 @@ fn show_precedence_cla(term: &Term, context_precedence: usize, depth: u32) -> St
             let (ref t1, ref t2) = **boxed;
             let ret = format!(
                 "{} {}",
-                show_precedence_cla(t1, 2, depth),
-                show_precedence_cla(t2, 3, depth)
+                show_precedence_cla(t1, 2, depth, binders),
+                show_precedence_cla(t2, 3, depth, binders)
             );
             parenthesize_if(&ret, context_precedence == 3).into()
         }
     }
 }
 
+/// Returns the greatest number of nested abstractions in the given `Term`.
+fn binder_depth(term: &Term) -> usize {
+    let mut max = 0;
+    let mut stack = vec![(0, term)];
+
+    while let Some((depth, term)) = stack.pop() {
+        match term {
+            Var(_) => max = max.max(depth),
+            Abs(ref t) => stack.push((depth + 1, t)),
+            App(boxed) => {
+                let (ref lhs, ref rhs) = **boxed;
+                stack.push((depth, lhs));
+                stack.push((depth, rhs));
+            }
+        }
+    }
+
+    max
+}
+
+/// Produces the name of the variable with the given De Bruijn index at the given depth in a term
+/// with at most `binders` nested abstractions; the free variables are named after all the bound
+/// ones, so that a free variable has the same name at every depth and is never captured.
+fn classic_name(index: usize, depth: usize, binders: usize) -> String {
+    if depth >= index {
+        variable_name(depth - index)
+    } else {
+        variable_name(binders + index - depth - 1)
+    }
+}
+
+/// Produces the name of the variable bound by the `index`th abstraction (counting from zero): a, b,
+/// ..., z, aa, ab, ..., az, ba, ...
+fn variable_name(index: usize) -> String {
+    let mut name = Vec::new();
+    let mut n = index + 1;
+
+    while n > 0 {
+        n -= 1;
+        name.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+
+    name.iter().rev().map(|&b| b as char).collect()
+}
+
+/// Displays the `Term` in the `DeBruijn` notation; with the alternate flag (`{:#?}`) only the
+/// parentheses that are necessary to parse it back are used.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+///
+/// let term = abs(app(Var(1), abs(Var(2)))); // λ 1 (λ 2)
+///
+/// assert_eq!(format!("{:?}", term), "λ1(λ2)");
+/// assert_eq!(format!("{:#?}", term), "λ1λ2");
+/// ```
 impl fmt::Debug for Term {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", show_precedence_dbr(self, 0))
+        if f.alternate() {
+            write!(
+                f,
+                "{}",
+                show_minimal(self, DeBruijn, Position::Top, true, 0, 0)
+            )
+        } else {
+            write!(f, "{}", show_precedence_dbr(self, 0))
+        }
     }
 }
 
@@ -460,7 +964,7 @@ fn show_precedence_dbr(term: &Term, context_precedence: usize) -> String {
     match term {
         Var(0) => "undefined".to_owned(),
         Var(i) => {
-            format!("{:X}", i)
+            format!("{}", i)
         }
         Abs(ref t) => {
             let ret = format!("{}{:?}", LAMBDA, t);
@@ -468,17 +972,93 @@ fn show_precedence_dbr(term: &Term, context_precedence: usize) -> String {
         }
         App(boxed) => {
             let (ref t1, ref t2) = **boxed;
-            let ret = format!(
-                "{}{}",
-                show_precedence_dbr(t1, 2),
-                show_precedence_dbr(t2, 3)
-            );
+            let (lhs, rhs) = (show_precedence_dbr(t1, 2), show_precedence_dbr(t2, 3));
+            let separator = if lhs.ends_with(char::is_numeric) && rhs.starts_with(char::is_numeric)
+            {
+                " " // consecutive digits would be parsed as a single index
+            } else {
+                ""
+            };
+            let ret = format!("{}{}{}", lhs, separator, rhs);
             parenthesize_if(&ret, context_precedence == 3).into()
         }
     }
 }
 
-fn parenthesize_if(input: &str, condition: bool) -> Cow<str> {
+/// The position of a `Term` within its parent.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Position {
+    /// the whole term or the body of an abstraction
+    Top,
+    /// the left-hand side of an application
+    Function,
+    /// the right-hand side of an application
+    Argument,
+}
+
+/// Shows a `Term` with the minimal number of parentheses; an abstraction extends as far right as
+/// possible, so it only needs them if it is applied or if it is an argument followed by other
+/// terms (`rightmost` is `false`). `binders` is only used to name the free variables in the
+/// `Classic` notation.
+fn show_minimal(
+    term: &Term,
+    notation: Notation,
+    position: Position,
+    rightmost: bool,
+    depth: usize,
+    binders: usize,
+) -> String {
+    match term {
+        Var(0) => "undefined".to_owned(),
+        Var(i) => match notation {
+            Classic | Ascii => classic_name(*i, depth, binders),
+            DeBruijn => i.to_string(),
+        },
+        Abs(ref t) => {
+            let parenthesized =
+                position == Position::Function || (position == Position::Argument && !rightmost);
+            let body = show_minimal(
+                t,
+                notation,
+                Position::Top,
+                rightmost || parenthesized,
+                depth + 1,
+                binders,
+            );
+            let ret = match notation {
+                Classic | Ascii => format!("{}{}.{}", LAMBDA, variable_name(depth), body),
+                DeBruijn => format!("{}{}", LAMBDA, body),
+            };
+            parenthesize_if(&ret, parenthesized).into()
+        }
+        App(boxed) => {
+            let (ref t1, ref t2) = **boxed;
+            let parenthesized = position == Position::Argument;
+            let lhs = show_minimal(t1, notation, Position::Function, false, depth, binders);
+            let rhs = show_minimal(
+                t2,
+                notation,
+                Position::Argument,
+                rightmost || parenthesized,
+                depth,
+                binders,
+            );
+            let separator = match notation {
+                Classic | Ascii => " ",
+                DeBruijn
+                    if lhs.ends_with(char::is_numeric) && rhs.starts_with(char::is_numeric) =>
+                {
+                    " "
+                }
+                DeBruijn => "",
+            };
+            let ret = format!("{}{}{}", lhs, separator, rhs);
+            parenthesize_if(&ret, parenthesized).into()
+        }
+    }
+}
+
+fn parenthesize_if(input: &str, condition: bool) -> Cow<'_, str> {
     if condition {
         format!("({})", input).into()
     } else {
@@ -486,6 +1066,49 @@ fn parenthesize_if(input: &str, condition: bool) -> Cow<str> {
     }
 }
 
+/// Parses a `Term` in the `DeBruijn` notation, i.e. the one used by its `Debug` implementation;
+/// it is equivalent to `parse(input, DeBruijn)`.
+///
+/// # Examples
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::parser::ParseError;
+///
+/// assert_eq!("λ1".parse::<Term>(), Ok(abs(Var(1))));
+/// assert_eq!(
+///     "λ1 x".parse::<Term>(),
+///     Err(ParseError::InvalidCharacter { line: 1, column: 4, character: 'x' })
+/// );
+/// ```
+impl FromStr for Term {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse(input, DeBruijn)
+    }
+}
+
+/// Applies one `Term` to another with the `*` operator, i.e. `f * x` is `app(f, x)`. Like
+/// multiplication in Rust and application in lambda calculus, it is left-associative, so
+/// `f * x * y` is `(f x) y`; a nested application on the right has to be parenthesized.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::*;
+/// use lambda_calculus::combinators::{I, K, S};
+///
+/// assert_eq!(K() * I(), app(K(), I()));
+/// assert_eq!(S() * K() * K(), app!(S(), K(), K()));
+/// assert_eq!(S() * (K() * K()), app(S(), app(K(), K())));
+/// ```
+impl Mul for Term {
+    type Output = Term;
+
+    fn mul(self, rhs: Term) -> Term {
+        app(self, rhs)
+    }
+}
+
 /// A macro for chain application of `Term`s.
 ///
 /// # Example
@@ -544,6 +1167,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mul_operator() {
+        let (f, x, y) = (Var(1), Var(2), Var(3));
+
+        assert_eq!(f.clone() * x.clone(), app(f.clone(), x.clone()));
+        assert_eq!(
+            f.clone() * x.clone() * y.clone(),
+            app!(f.clone(), x.clone(), y.clone())
+        );
+        assert_eq!(
+            f.clone() * (x.clone() * y.clone()),
+            app(f.clone(), app(x.clone(), y.clone()))
+        );
+        assert_eq!(
+            abs(f.clone() * x.clone()) * y.clone(),
+            app(abs(app(f, x)), y)
+        );
+    }
+
+    #[test]
+    fn abs_n() {
+        assert_eq!(super::abs_n(3, Var(1)), abs!(3, Var(1)));
+
+        for n in 0..5 {
+            let term = super::abs_n(n, app(Var(1), Var(n)));
+
+            assert_eq!(term, abs!(n, app(Var(1), Var(n))));
+            assert_eq!(term.depth(), n + 2);
+        }
+    }
+
+    #[test]
+    fn applied_to() {
+        let f_a_b_c = parse("λf.λa.λb.λc.f a b c", Classic).unwrap();
+        let args = [Var(3), Var(2), Var(1)];
+
+        assert_eq!(abs!(4, Var(4).applied_to(&args)), f_a_b_c);
+        assert_eq!(
+            Var(4).applied_to(&args),
+            app!(Var(4), Var(3), Var(2), Var(1))
+        );
+        assert_eq!(Var(4).applied_to(&args[..1]), app(Var(4), Var(3)));
+        assert_eq!(Var(4).applied_to(&[]), Var(4));
+    }
+
     #[test]
     fn abs_macro() {
         assert_eq!(abs!(4, Var(1)), abs(abs(abs(abs(Var(1))))));
@@ -581,8 +1249,8 @@ mod tests {
         );
 
         assert_eq!(&format!("{:?}", zero), "λλ1");
-        assert_eq!(&format!("{:?}", succ), "λλλ2(321)");
-        assert_eq!(&format!("{:?}", pred), "λλλ3(λλ1(24))(λ2)(λ1)");
+        assert_eq!(&format!("{:?}", succ), "λλλ2(3 2 1)");
+        assert_eq!(&format!("{:?}", pred), "λλλ3(λλ1(2 4))(λ2)(λ1)");
     }
 
     #[test]
@@ -598,4 +1266,265 @@ mod tests {
         assert!(!abs!(10, Var(11)).is_supercombinator());
         assert!(!abs!(10, app(Var(10), Var(11))).is_supercombinator());
     }
+
+    #[test]
+    fn free_variables() {
+        assert_eq!(abs(Var(2)).free_variables(), vec![1]);
+        assert_eq!(abs!(2, app(Var(2), Var(1))).free_variables(), vec![]);
+        assert_eq!(Var(3).free_variables(), vec![3]);
+        assert_eq!(
+            app!(Var(2), abs(Var(3)), abs!(2, Var(4)), Var(1)).free_variables(),
+            vec![1, 2]
+        );
+
+        assert!(abs!(2, app(Var(2), Var(1))).is_closed());
+        assert!(!abs(Var(2)).is_closed());
+        assert!(!app(abs(Var(1)), Var(1)).is_closed());
+    }
+
+    #[test]
+    fn alpha_eq() {
+        let named = parse("λf.λx.f (λy.y x) (λf.f)", Classic).unwrap();
+        let renamed = parse("λg.λa.g (λb.b a) (λx.x)", Classic).unwrap();
+        let built = abs!(2, app!(Var(2), abs(app(Var(1), Var(2))), abs(Var(1))));
+
+        assert!(named.alpha_eq(&renamed));
+        assert!(named.alpha_eq(&built));
+        assert!(!named.alpha_eq(&abs!(
+            2,
+            app!(Var(2), abs(app(Var(1), Var(3))), abs(Var(1)))
+        )));
+
+        // the same free variable reached from different depths
+        let mut free = Var(1);
+        free.shift(1, 0);
+        assert!(abs(app(Var(1), free)).alpha_eq(&abs(app(Var(1), Var(2)))));
+
+        let mut shifted = abs(app(Var(1), Var(3)));
+        shifted.shift(2, 0);
+        shifted.shift(-2, 0);
+        assert!(shifted.alpha_eq(&abs(app(Var(1), Var(3)))));
+        assert!(!abs(Var(2)).alpha_eq(&abs(Var(3))));
+    }
+
+    #[test]
+    fn eta_eq() {
+        use crate::combinators::{I, K, S, Y};
+        use crate::reduction::eta;
+
+        // λ f 1 with a closed f
+        for f in [I(), K(), S(), Y()] {
+            assert!(abs(app(f.clone(), Var(1))).eta_eq(&f));
+            assert!(f.eta_eq(&abs(app(f.clone(), Var(1)))));
+        }
+        assert!(!abs(app(Var(1), Var(1))).eta_eq(&Var(1)));
+        assert!(!abs(app(Var(1), Var(1))).eta_eq(&I()));
+
+        // free variables are seen from the outside of the abstraction
+        assert!(abs(app(Var(2), Var(1))).eta_eq(&Var(1)));
+        assert!(!abs(app(Var(2), Var(1))).eta_eq(&Var(2)));
+
+        // the redex can be nested on either side
+        assert!(abs(app(I(), Var(1))).eta_eq(&I()));
+        let expanded = abs!(2, app!(Var(2), abs(app(Var(2), Var(1))))); // λ λ 2 (λ 2 1)
+        let reduced = abs(Var(1)); // λ 1
+        assert!(expanded.eta_eq(&reduced));
+        assert!(reduced.eta_eq(&expanded));
+        assert!(app(K(), expanded).eta_eq(&app(abs(app(K(), Var(1))), reduced)));
+
+        // consistency with η-normal forms
+        for term in [
+            S(),
+            Y(),
+            abs!(3, app!(Var(4), Var(2), Var(1))),
+            abs!(2, app(Var(1), Var(2))),
+            abs(app!(abs(app(Var(3), Var(1))), Var(1))),
+        ] {
+            let normal = eta(term.clone(), 0);
+            assert!(term.eta_eq(&normal));
+            assert!(normal.eta_eq(&term));
+        }
+        assert!(!S().eta_eq(&K()));
+    }
+
+    #[test]
+    fn size_and_depth() {
+        assert_eq!(Var(1).size(), 1);
+        assert_eq!(Var(1).depth(), 1);
+        assert_eq!(app(Var(1), abs!(3, Var(1))).size(), 6);
+        assert_eq!(app(Var(1), abs!(3, Var(1))).depth(), 5);
+    }
+
+    #[test]
+    fn subterms() {
+        // λ 1 (λ 1 2) (2 3)
+        let term = abs(app!(Var(1), abs(app(Var(1), Var(2))), app(Var(2), Var(3))));
+
+        assert_eq!(term.subterms().count(), term.size());
+        assert_eq!(term.subterms().filter(|t| matches!(t, App(_))).count(), 4);
+        assert_eq!(
+            term.subterms()
+                .filter_map(|t| t.unvar_ref().ok())
+                .collect::<Vec<_>>(),
+            vec![&1, &1, &2, &2, &3]
+        );
+    }
+
+    #[test]
+    fn for_each_subterm_mut() {
+        // replace all the applications of 1 with λ 1, then visit the replacements
+        let mut term = abs(app(Var(1), app(Var(1), Var(1))));
+        let mut visited = 0;
+
+        term.for_each_subterm_mut(|t| {
+            visited += 1;
+            if let App(_) = *t {
+                if t.lhs_ref() == Ok(&Var(1)) {
+                    *t = abs(Var(1));
+                }
+            }
+        });
+
+        assert_eq!(term, abs(abs(Var(1))));
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn hash_and_ord() {
+        use crate::parser::parse;
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::{BTreeSet, HashSet};
+        use std::hash::{Hash, Hasher};
+
+        fn hash(term: &Term) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            term.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let k1 = parse("λx.λy.x", Classic).unwrap();
+        let k2 = parse("λa.λb.a", Classic).unwrap();
+        let k3 = abs!(2, Var(2));
+
+        assert_eq!(hash(&k1), hash(&k2));
+        assert_eq!(hash(&k1), hash(&k3));
+        assert_eq!(
+            vec![k1.clone(), k2.clone(), k3.clone()]
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .len(),
+            1
+        );
+
+        let ordered: BTreeSet<Term> = vec![app(Var(1), Var(1)), k1, abs(Var(1)), Var(2), Var(1)]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            ordered.into_iter().collect::<Vec<_>>(),
+            vec![Var(1), Var(2), abs(Var(1)), k2, app(Var(1), Var(1))]
+        );
+    }
+
+    #[test]
+    fn to_dot() {
+        // λ λ 2 (1 2)
+        let term = abs!(2, app(Var(2), app(Var(1), Var(2))));
+        let dot = term.to_dot();
+
+        assert_eq!(dot.matches("[label=").count(), term.size());
+        assert_eq!(dot.matches(" -> ").count(), term.size() - 1);
+        assert_eq!(
+            dot,
+            format!(
+                "digraph term {{
+    0 [label=\"{0}\"];
+    1 [label=\"{0}\"];
+    0 -> 1;
+    2 [label=\"@\"];
+    1 -> 2;
+    3 [label=\"2\"];
+    2 -> 3;
+    4 [label=\"@\"];
+    2 -> 4;
+    5 [label=\"1\"];
+    4 -> 5;
+    6 [label=\"2\"];
+    4 -> 6;
+}}",
+                LAMBDA
+            )
+        );
+    }
+
+    #[test]
+    fn to_string_as() {
+        use crate::combinators::Y;
+
+        assert_eq!(
+            Y().to_string_as(Classic),
+            format!("{0}a.({0}b.a (b b)) ({0}b.a (b b))", LAMBDA)
+        );
+        assert_eq!(
+            Y().to_string_as(DeBruijn),
+            format!("{0}({0}2(1 1))({0}2(1 1))", LAMBDA)
+        );
+        assert_eq!(abs(Var(2)).to_string_as(Classic), format!("{}a.b", LAMBDA));
+        assert_eq!(abs(Var(2)).to_string_as(DeBruijn), format!("{}2", LAMBDA));
+        assert_eq!(Y().to_string_as(Ascii), "\\a.(\\b.a (b b)) (\\b.a (b b))");
+        assert_eq!(Y().to_string_as(Ascii), Y().to_ascii_string());
+
+        for notation in [Classic, DeBruijn, Ascii] {
+            assert_eq!(
+                crate::parser::parse(&Y().to_string_as(notation), notation),
+                Ok(Y())
+            );
+        }
+    }
+
+    #[test]
+    fn free_variable_names() {
+        // the free variable is seen as 2 and as 3, but it has a single name
+        let term = abs(app(Var(2), abs(Var(3))));
+        assert_eq!(
+            term.to_string_as(Classic),
+            format!("{0}a.c ({0}b.c)", LAMBDA)
+        );
+        assert_eq!(format!("{:#}", term), format!("{0}a.c {0}b.c", LAMBDA));
+
+        // distinct free variables get distinct names, none of them used by a binder
+        let term = abs(app(Var(3), abs(app(Var(3), Var(4)))));
+        assert_eq!(
+            term.to_string_as(Classic),
+            format!("{0}a.d ({0}b.c d)", LAMBDA)
+        );
+        assert_eq!(term.to_string_as(Ascii), "\\a.d (\\b.c d)");
+    }
+
+    #[test]
+    fn minimal_parentheses() {
+        let succ = abs!(3, app(Var(2), app!(Var(3), Var(2), Var(1))));
+        let term = abs!(2, app!(Var(1), abs(Var(1)), abs(app(Var(3), Var(1)))));
+
+        assert_eq!(
+            format!("{:#}", succ),
+            format!("{0}a.{0}b.{0}c.b (a b c)", LAMBDA)
+        );
+        assert_eq!(format!("{:#?}", succ), format!("{0}{0}{0}2(3 2 1)", LAMBDA));
+        assert_eq!(
+            format!("{:#}", term),
+            format!("{0}a.{0}b.b ({0}c.c) {0}c.a c", LAMBDA)
+        );
+        assert_eq!(
+            format!("{:#?}", term),
+            format!("{0}{0}1({0}1){0}3 1", LAMBDA)
+        );
+        assert_eq!(
+            format!("{:#}", app(abs(Var(1)), abs(Var(1)))),
+            format!("({0}a.a) {0}a.a", LAMBDA)
+        );
+        assert_eq!(
+            format!("{:#?}", app(Var(1), app(Var(2), abs(Var(1))))),
+            format!("1(2{}1)", LAMBDA)
+        );
+    }
 }
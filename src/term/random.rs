@@ -0,0 +1,52 @@
+//! Random generation of lambda `Term`s
+
+use crate::term::Term::*;
+use crate::term::{abs, app, Term};
+use rand::Rng;
+
+/// Generates a random closed `Term` whose [depth](Term::depth) doesn't exceed `depth`. The
+/// abstractions, applications and variables are chosen with equal probability wherever they are
+/// allowed, and variables are bound by a randomly chosen enclosing abstraction.
+///
+/// # Example
+/// ```
+/// use lambda_calculus::term::random::random_closed;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let term = random_closed(10, &mut rng);
+///
+/// assert!(term.is_closed());
+/// assert!(term.depth() <= 10);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `depth` is lower than `2`, the depth of the smallest closed term, `λ1`.
+pub fn random_closed<R: Rng + ?Sized>(depth: usize, rng: &mut R) -> Term {
+    assert!(depth >= 2, "a closed term has a depth of at least 2");
+
+    random_term(rng, 0, depth)
+}
+
+/// Generates a random `Term` of at most the given `depth` and with free variables bound by one
+/// of the `binders` abstractions enclosing it.
+fn random_term<R: Rng + ?Sized>(rng: &mut R, binders: usize, depth: usize) -> Term {
+    // without enclosing abstractions a variable would be free
+    if binders == 0 {
+        return abs(random_term(rng, 1, depth - 1));
+    }
+    if depth == 1 {
+        return Var(rng.gen_range(1..=binders));
+    }
+
+    match rng.gen_range(0..3) {
+        0 => Var(rng.gen_range(1..=binders)),
+        1 => abs(random_term(rng, binders + 1, depth - 1)),
+        _ => app(
+            random_term(rng, binders, depth - 1),
+            random_term(rng, binders, depth - 1),
+        ),
+    }
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "encoding")]
+
+extern crate lambda_calculus as lambda;
+
+use lambda::data::boolean::*;
+use lambda::*;
+
+macro_rules! test_truth_table {
+    ($name:ident, $function:ident, $operator:expr) => {
+        #[test]
+        fn $name() {
+            for &p in &[true, false] {
+                for &q in &[true, false] {
+                    assert_eq!(
+                        beta(app!($function(), p.into(), q.into()), HAP, 0),
+                        Term::from($operator(p, q))
+                    );
+                }
+            }
+        }
+    };
+}
+
+test_truth_table!(boolean_and, and, |p, q| p && q);
+test_truth_table!(boolean_or, or, |p, q| p || q);
+test_truth_table!(boolean_xor, xor, |p, q| p ^ q);
+test_truth_table!(boolean_nor, nor, |p: bool, q: bool| !(p || q));
+test_truth_table!(boolean_xnor, xnor, |p, q| p == q);
+test_truth_table!(boolean_nand, nand, |p: bool, q: bool| !(p && q));
+test_truth_table!(boolean_imply, imply, |p: bool, q| !p || q);
+
+#[test]
+fn boolean_not() {
+    assert_eq!(beta(app(not(), true.into()), HAP, 0), false.into());
+    assert_eq!(beta(app(not(), false.into()), HAP, 0), true.into());
+}
+
+#[test]
+fn boolean_if_else() {
+    for &p in &[true, false] {
+        assert_eq!(
+            beta(app!(if_else(), p.into(), Var(1), Var(2)), HAP, 0),
+            if p { Var(1) } else { Var(2) }
+        );
+    }
+}
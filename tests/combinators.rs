@@ -0,0 +1,55 @@
+extern crate lambda_calculus as lambda;
+
+use lambda::combinators::*;
+use lambda::*;
+
+/// Returns `true` if the `Term` consists only of applications of `S`, `K`, `I` and variables.
+fn is_ski(term: &Term) -> bool {
+    match term {
+        Var(_) => true,
+        Abs(_) => *term == S() || *term == K() || *term == I(),
+        App(boxed) => is_ski(&boxed.0) && is_ski(&boxed.1),
+    }
+}
+
+#[test]
+fn ski_translation() {
+    let terms = vec![I(), K(), S(), B(), C(), W(), R(), U(), i(), abs!(3, Var(4))];
+
+    for term in &terms {
+        let ski = to_ski(term);
+
+        assert!(is_ski(&ski));
+        assert_eq!(beta(ski.clone(), NOR, 0), beta(term.clone(), NOR, 0));
+        assert_eq!(
+            beta(app!(ski, Var(1), Var(2), Var(3)), NOR, 0),
+            beta(app!(term.clone(), Var(1), Var(2), Var(3)), NOR, 0)
+        );
+    }
+}
+
+#[test]
+fn ski_translation_of_identity() {
+    let ski = to_ski(&abs(app(abs(Var(1)), Var(1)))); // λ (λ 1) 1
+
+    assert!(is_ski(&ski));
+    for argument in [Var(1), K(), app(Var(2), Var(3)), S()] {
+        assert_eq!(beta(app(ski.clone(), argument.clone()), NOR, 0), argument);
+    }
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn ski_translation_of_encodings() {
+    use lambda::data::num::church::{add, mul, succ};
+
+    for function in [succ(), add(), mul()] {
+        let ski = to_ski(&function);
+
+        assert!(is_ski(&ski));
+        assert_eq!(
+            beta(app!(ski, 2.into_church(), 3.into_church()), NOR, 0),
+            beta(app!(function, 2.into_church(), 3.into_church()), NOR, 0)
+        );
+    }
+}
@@ -27,6 +27,20 @@ macro_rules! test_list {
     );
 }
 
+macro_rules! test_church_list {
+    ($name:ident, $function:ident, $($($n:expr),+ => $result:expr),+) => (
+        #[test]
+        fn $name() {
+            $(
+                assert_eq!(
+                    beta(app!(church::$function(), $($n.into_church()),*), HAP, 0),
+                    $result.into_church()
+                );
+            )*
+        }
+    );
+}
+
 fn nil() -> Vec<Term> {
     vec![]
 } // a nil workaround for macro purposes
@@ -43,3 +57,482 @@ test_list!(list_tail, tail,
        vec![1, 2, 3] =>    vec![2, 3],
     vec![1, 2, 3, 4] => vec![2, 3, 4]
 );
+
+test_church_list!(church_list_append, append,
+            nil(),      nil() =>             nil(),
+            nil(),    vec![1] =>           vec![1],
+          vec![1],      nil() =>           vec![1],
+       vec![1, 2],    vec![3] =>     vec![1, 2, 3],
+    vec![1, 2, 3], vec![4, 5] => vec![1, 2, 3, 4, 5]
+);
+
+test_church_list!(church_list_reverse, reverse,
+            nil() =>         nil(),
+          vec![1] =>       vec![1],
+    vec![1, 2, 3] => vec![3, 2, 1]
+);
+
+#[test]
+fn church_list_map_filter() {
+    use lambda::data::num::church::{is_zero, succ};
+
+    for (list, mapped) in [
+        (vec![], vec![]),
+        (vec![0], vec![1]),
+        (vec![1, 2, 3], vec![2, 3, 4]),
+    ] {
+        assert_eq!(
+            beta(app!(church::map(), succ(), list.into_church()), HAP, 0),
+            mapped.into_church()
+        );
+    }
+
+    for (list, filtered) in [
+        (vec![], vec![]),
+        (vec![1, 2], vec![]),
+        (vec![0, 1, 0], vec![0, 0]),
+        (vec![0, 0], vec![0, 0]),
+    ] {
+        assert_eq!(
+            beta(
+                app!(church::filter(), is_zero(), list.into_church()),
+                HAP,
+                0
+            ),
+            filtered.into_church()
+        );
+    }
+}
+
+#[test]
+fn church_list_folds() {
+    use lambda::data::num::church::{add, sub};
+
+    for (list, sum) in [(vec![], 0), (vec![1], 1), (vec![1, 2, 3], 6)] {
+        assert_eq!(
+            beta(
+                app!(
+                    church::foldr(),
+                    add(),
+                    0.into_church(),
+                    list.clone().into_church()
+                ),
+                HAP,
+                0
+            ),
+            sum.into_church()
+        );
+        assert_eq!(
+            beta(
+                app!(
+                    church::foldl(),
+                    add(),
+                    0.into_church(),
+                    list.clone().into_church()
+                ),
+                HAP,
+                0
+            ),
+            sum.into_church()
+        );
+        assert_eq!(
+            beta(app(church::length(), list.clone().into_church()), HAP, 0),
+            list.len().into_church()
+        );
+    }
+
+    // 3 - (2 - (1 - 0)) = 2 vs ((3 - 3) - 2) - 1 = 0
+    let list = vec![3, 2, 1];
+    assert_eq!(
+        beta(
+            app!(
+                church::foldr(),
+                sub(),
+                0.into_church(),
+                list.clone().into_church()
+            ),
+            HAP,
+            0
+        ),
+        2.into_church()
+    );
+    assert_eq!(
+        beta(
+            app!(church::foldl(), sub(), 3.into_church(), list.into_church()),
+            HAP,
+            0
+        ),
+        0.into_church()
+    );
+}
+
+test_church_list!(church_list_sum, sum,
+            nil() => 0,
+          vec![5] => 5,
+    vec![1, 2, 3] => 6
+);
+
+test_church_list!(church_list_product, product,
+               nil() => 1,
+             vec![5] => 5,
+       vec![2, 3, 4] => 24,
+    vec![2, 0, 4, 1] => 0
+);
+
+test_church_list!(church_list_sort, sort,
+                     nil() =>                nil(),
+                   vec![1] =>              vec![1],
+             vec![2, 1, 2] =>        vec![1, 2, 2],
+    vec![5, 3, 8, 1, 0, 2] => vec![0, 1, 2, 3, 5, 8]
+);
+
+#[test]
+fn church_list_contains() {
+    use lambda::data::num::church::eq;
+
+    for (n, list, contained) in [
+        (0, vec![], false),
+        (1, vec![1], true),
+        (2, vec![1, 2, 3], true),
+        (3, vec![1, 2, 3], true),
+        (0, vec![1, 2, 3], false),
+        (5, vec![1, 2, 3], false),
+    ] {
+        assert_eq!(
+            beta(
+                app!(
+                    church::contains_num(),
+                    n.into_church(),
+                    list.clone().into_church()
+                ),
+                HAP,
+                0
+            ),
+            contained.into()
+        );
+        assert_eq!(
+            beta(
+                app!(
+                    church::contains(),
+                    eq(),
+                    n.into_church(),
+                    list.into_church()
+                ),
+                HAP,
+                0
+            ),
+            contained.into()
+        );
+    }
+}
+
+#[test]
+fn church_list_find() {
+    use lambda::data::num::church::{is_zero, leq};
+
+    for (list, found) in [
+        (vec![], None),
+        (vec![1, 2, 3], None),
+        (vec![0], Some(0)),
+        (vec![1, 0, 2], Some(0)),
+    ] {
+        assert_eq!(
+            beta(app!(church::find(), is_zero(), list.into_church()), HAP, 0),
+            found.into_church()
+        );
+    }
+
+    // the first element that is at least 2
+    assert_eq!(
+        beta(
+            app!(
+                church::find(),
+                app(leq(), 2.into_church()),
+                vec![1, 3, 2].into_church()
+            ),
+            HAP,
+            0
+        ),
+        Some(3).into_church()
+    );
+}
+
+#[test]
+fn church_list_quantifiers() {
+    use lambda::data::num::church::is_zero;
+    use lambda::reduction::beta_counted;
+
+    for (list, all, any) in [
+        (vec![], true, false),
+        (vec![0], true, true),
+        (vec![1], false, false),
+        (vec![0, 0, 0], true, true),
+        (vec![1, 0, 2], false, true),
+        (vec![1, 2, 3], false, false),
+    ] {
+        assert_eq!(
+            beta(
+                app!(church::all(), is_zero(), list.clone().into_church()),
+                HAP,
+                0
+            ),
+            all.into()
+        );
+        assert_eq!(
+            beta(app!(church::any(), is_zero(), list.into_church()), HAP, 0),
+            any.into()
+        );
+    }
+
+    // the normal order stops at the first deciding element
+    let steps = |list: Vec<usize>| {
+        beta_counted(app!(church::any(), is_zero(), list.into_church()), NOR, 0).1
+    };
+    assert_eq!(steps(vec![0, 1]), steps(vec![0, 1, 2, 3, 4, 5]));
+}
+
+#[test]
+fn church_list_indexing() {
+    let list = || vec![10, 20, 30].into_church();
+
+    for (n, nth) in [(0, Some(10)), (1, Some(20)), (2, Some(30)), (3, None)] {
+        assert_eq!(
+            beta(app!(church::nth(), n.into_church(), list()), HAP, 0),
+            nth.into_church()
+        );
+    }
+    assert_eq!(
+        beta(app!(church::nth(), 0.into_church(), church::nil()), HAP, 0),
+        None::<usize>.into_church()
+    );
+
+    for n in 0..5 {
+        assert_eq!(
+            beta(app!(church::take(), n.into_church(), list()), HAP, 0),
+            vec![10, 20, 30]
+                .into_iter()
+                .take(n)
+                .collect::<Vec<_>>()
+                .into_church()
+        );
+        assert_eq!(
+            beta(app!(church::drop(), n.into_church(), list()), HAP, 0),
+            vec![10, 20, 30]
+                .into_iter()
+                .skip(n)
+                .collect::<Vec<_>>()
+                .into_church()
+        );
+    }
+}
+
+#[test]
+fn church_list_generators() {
+    for a in 0..4 {
+        for b in 0..4 {
+            assert_eq!(
+                beta(
+                    app!(church::range(), a.into_church(), b.into_church()),
+                    HAP,
+                    0
+                ),
+                (a..b).collect::<Vec<usize>>().into_church()
+            );
+        }
+    }
+
+    for n in 0..4 {
+        assert_eq!(
+            beta(
+                app!(church::replicate(), n.into_church(), 7.into_church()),
+                HAP,
+                0
+            ),
+            vec![7; n].into_church()
+        );
+    }
+}
+
+#[test]
+fn church_list_zip() {
+    use lambda::data::num::church::add;
+
+    for (list1, list2) in [
+        (vec![], vec![]),
+        (vec![1, 2], vec![]),
+        (vec![1, 2, 3], vec![10, 20, 30]),
+        (vec![1, 2, 3], vec![10]),
+        (vec![1], vec![10, 20, 30]),
+    ] {
+        let pairs: Vec<Term> = list1
+            .iter()
+            .zip(&list2)
+            .map(|(&a, &b)| (a, b).into_church())
+            .collect();
+        let sums: Vec<usize> = list1.iter().zip(&list2).map(|(a, b)| a + b).collect();
+
+        assert_eq!(
+            beta(
+                app!(
+                    church::zip(),
+                    list1.clone().into_church(),
+                    list2.clone().into_church()
+                ),
+                HAP,
+                0
+            ),
+            pairs.into_church()
+        );
+        assert_eq!(
+            beta(
+                app!(
+                    church::zip_with(),
+                    add(),
+                    list1.into_church(),
+                    list2.into_church()
+                ),
+                HAP,
+                0
+            ),
+            sums.into_church()
+        );
+    }
+}
+
+#[test]
+fn church_list_steps() {
+    use lambda::reduction::beta_counted;
+
+    let append_steps = |l1: Vec<usize>, l2: Vec<usize>| {
+        beta_counted(
+            app!(church::append(), l1.into_church(), l2.into_church()),
+            NOR,
+            0,
+        )
+        .1
+    };
+    let reverse_steps =
+        |l: Vec<usize>| beta_counted(app(church::reverse(), l.into_church()), NOR, 0).1;
+
+    assert_eq!(append_steps(vec![], vec![]), 6);
+    assert_eq!(append_steps(vec![1; 10], vec![1; 10]), 6);
+    assert_eq!(reverse_steps(vec![]), 4);
+    assert_eq!(reverse_steps(vec![1; 10]), 34);
+}
+
+#[test]
+fn scott_list_constant_time_deconstruction() {
+    use lambda::reduction::beta_counted;
+
+    let steps = |list: Term| beta_counted(app(scott::tail(), list), NOR, 0).1;
+    let church_steps = |list: Term| beta_counted(app(church::tail(), list), NOR, 0).1;
+
+    let list = app!(
+        scott::cons(),
+        1.into_scott(),
+        app!(
+            scott::cons(),
+            2.into_scott(),
+            app!(scott::cons(), 3.into_scott(), scott::nil())
+        )
+    );
+    assert_eq!(
+        beta(app(scott::head(), list.clone()), NOR, 0),
+        1.into_scott()
+    );
+    assert_eq!(
+        beta(app(scott::tail(), list), NOR, 0),
+        vec![2, 3].into_scott()
+    );
+
+    assert_eq!(steps(vec![1].into_scott()), steps(vec![1; 10].into_scott()));
+    assert!(church_steps(vec![1].into_church()) < church_steps(vec![1; 10].into_church()));
+}
+
+#[test]
+fn scott_list_case() {
+    let list = || {
+        app!(
+            scott::cons(),
+            1.into_scott(),
+            app!(
+                scott::cons(),
+                2.into_scott(),
+                app!(scott::cons(), 3.into_scott(), scott::nil())
+            )
+        )
+    };
+    let case = |list, nil, cons| beta(app!(scott::case(), list, nil, cons), NOR, 0);
+
+    assert_eq!(case(list(), UD, abs!(2, Var(2))), 1.into_scott());
+    assert_eq!(case(list(), UD, abs!(2, Var(1))), vec![2, 3].into_scott());
+    assert_eq!(
+        case(list(), true.into(), abs!(2, false.into())),
+        false.into()
+    );
+    assert_eq!(
+        case(scott::nil(), true.into(), abs!(2, false.into())),
+        true.into()
+    );
+
+    // head, tail and is_nil are instances of case
+    for (function, nil, cons) in [
+        (scott::head(), UD, abs!(2, Var(2))),
+        (scott::tail(), UD, abs!(2, Var(1))),
+        (scott::is_nil(), true.into(), abs!(2, false.into())),
+    ] {
+        assert_eq!(
+            function,
+            beta(abs(app!(scott::case(), Var(1), nil, cons)), NOR, 0)
+        );
+    }
+}
+
+#[test]
+fn church_list_from_church() {
+    let mapped = beta(
+        app!(
+            church::map(),
+            lambda::data::num::church::succ(),
+            vec![1, 2, 3].into_church()
+        ),
+        NOR,
+        0,
+    );
+
+    assert_eq!(Vec::<usize>::from_church(mapped), Ok(vec![2, 3, 4]));
+    assert_eq!(Vec::<usize>::from_church(church::nil()), Ok(vec![]));
+    assert_eq!(
+        Vec::<bool>::from_church(vec![Term::from(true), Term::from(false)].into_church()),
+        Ok(vec![true, false])
+    );
+    assert_eq!(
+        Vec::<usize>::from_church(vec![1, 2].into_scott()),
+        Err(ConversionError::InvalidTerm)
+    );
+    assert_eq!(
+        Vec::<usize>::from_church(vec![Term::from(true)].into_church()),
+        Err(ConversionError::InvalidTerm)
+    );
+}
+
+#[test]
+fn church_string() {
+    let encoded = "AB".into_church();
+
+    assert_eq!(encoded, vec![65, 66].into_church());
+    assert_eq!(String::from_church(encoded), Ok("AB".to_string()));
+    assert_eq!(String::from_church("".into_church()), Ok(String::new()));
+    assert_eq!(
+        String::from_church(String::from("λx.x").into_church()),
+        Ok("λx.x".to_string())
+    );
+    assert_eq!(
+        beta(app(church::length(), "λx.x".into_church()), HAP, 0),
+        4.into_church()
+    );
+    assert_eq!(
+        String::from_church(vec![0xD800].into_church()),
+        Err(ConversionError::InvalidTerm)
+    );
+}
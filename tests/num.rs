@@ -3,6 +3,7 @@
 extern crate lambda_calculus as lambda;
 
 use lambda::data::num::{binary, church, parigot, scott, stumpfu};
+use lambda::data::pair;
 use lambda::*;
 
 macro_rules! test_num {
@@ -34,6 +35,7 @@ test_num_all!(num_succ, succ,
 );
 
 test_num_all!(num_pred, pred,
+    0 => 0,
     1 => 0,
     2 => 1,
     3 => 2
@@ -75,7 +77,8 @@ test_num!(church, church_sub, into_church, sub,
     0, 0 => 0,
     0, 1 => 0,
     1, 0 => 1,
-    3, 2 => 1
+    3, 2 => 1,
+    3, 5 => 0
 );
 /*
 test_num!(scott, scott_sub, into_scott, sub,
@@ -89,7 +92,8 @@ test_num!(parigot, parigot_sub, into_parigot, sub,
     0, 0 => 0,
     0, 1 => 0,
     1, 0 => 1,
-    3, 2 => 1
+    3, 2 => 1,
+    3, 5 => 0
 );
 /*
 test_num!(stumpfu, stumpfu_sub, into_stumpfu, sub,
@@ -145,7 +149,9 @@ test_num!(church, church_pow, into_church, pow,
     1, 0 => 1,
     1, 2 => 1,
     2, 1 => 2,
-    3, 2 => 9
+    3, 2 => 9,
+    2, 3 => 8,
+    5, 0 => 1
 );
 
 test_num!(scott, scott_pow, into_scott, pow,
@@ -182,22 +188,22 @@ test_num!(church,  church_div,  into_church,  div, 0, 1 => (0, 0), 2, 1 => (2, 0
 //test_num!(parigot, parigot_div, into_parigot, div, 0, 1 => (0, 0), 2, 1 => (2, 0), 1, 2 => (0, 1), 5, 2 => (2, 1));
 //test_num!(stumpfu, stumpfu_div, into_stumpfu, div, 0, 1 => (0, 0), 2, 1 => (2, 0), 1, 2 => (0, 1), 5, 2 => (2, 1));
 
-test_num!(church,  church_quot,  into_church,  quot, 0, 1 => 0, 2, 1 => 2, 3, 2 => 1, 5, 2 => 2);
+test_num!(church,  church_quot,  into_church,  quot, 0, 1 => 0, 2, 1 => 2, 3, 2 => 1, 5, 2 => 2, 7, 2 => 3);
 //test_num!(scott,   scott_quot,   into_scott,   quot, 0, 1 => 0, 2, 1 => 2, 3, 2 => 1, 5, 2 => 2);
 //test_num!(parigot, parigot_quot, into_parigot, quot, 0, 1 => 0, 2, 1 => 2, 3, 2 => 1, 5, 2 => 2);
 //test_num!(stumpfu, stumpfu_quot, into_stumpfu, quot, 0, 1 => 0, 2, 1 => 2, 3, 2 => 1, 5, 2 => 2);
 
-test_num!(church,  church_rem,  into_church,  rem, 0, 1 => 0, 2, 1 => 0, 3, 2 => 1, 5, 2 => 1);
+test_num!(church,  church_rem,  into_church,  rem, 0, 1 => 0, 2, 1 => 0, 3, 2 => 1, 5, 2 => 1, 7, 2 => 1);
 //test_num!(scott,   scott_rem,   into_scott,   rem, 0, 1 => 0, 2, 1 => 0, 3, 2 => 1, 5, 2 => 1);
 //test_num!(parigot, parigot_rem, into_parigot, rem, 0, 1 => 0, 2, 1 => 0, 3, 2 => 1, 5, 2 => 1);
 //test_num!(stumpfu, stumpfu_rem, into_stumpfu, rem, 0, 1 => 0, 2, 1 => 0, 3, 2 => 1, 5, 2 => 1);
 
-test_num!(church,  church_min,  into_church,  min, 0, 0 => 0, 0, 1 => 0, 1, 0 => 0, 1, 2 => 1);
+test_num!(church,  church_min,  into_church,  min, 0, 0 => 0, 0, 1 => 0, 1, 0 => 0, 1, 2 => 1, 3, 5 => 3, 5, 3 => 3, 4, 4 => 4);
 //test_num!(scott,   scott_min,   into_scott,   min, 0, 0 => 0, 0, 1 => 0, 1, 0 => 0, 1, 2 => 1);
 //test_num!(parigot, parigot_min, into_parigot, min, 0, 0 => 0, 0, 1 => 0, 1, 0 => 0, 1, 2 => 1);
 //test_num!(stumpfu, stumpfu_min, into_stumpfu, min, 0, 0 => 0, 0, 1 => 0, 1, 0 => 0, 1, 2 => 1);
 
-test_num!(church,  church_max,  into_church,  max, 0, 0 => 0, 0, 1 => 1, 1, 0 => 1, 1, 2 => 2);
+test_num!(church,  church_max,  into_church,  max, 0, 0 => 0, 0, 1 => 1, 1, 0 => 1, 1, 2 => 2, 3, 5 => 5, 5, 3 => 5, 4, 4 => 4);
 //test_num!(scott,   scott_max,   into_scott,   max, 0, 0 => 0, 0, 1 => 1, 1, 0 => 1, 1, 2 => 2);
 //test_num!(parigot, parigot_max, into_parigot, max, 0, 0 => 0, 0, 1 => 1, 1, 0 => 1, 1, 2 => 2);
 //test_num!(stumpfu, stumpfu_max, into_stumpfu, max, 0, 0 => 0, 0, 1 => 1, 1, 0 => 1, 1, 2 => 2);
@@ -216,3 +222,482 @@ test_num!(church,  church_fac,  into_church,  fac, 0 => 1, 1 => 1, 2 => 2, 3 =>
 //test_num!(scott,   scott_fac,   into_scott,   fac, 0 => 1, 1 => 1, 2 => 2, 3 => 6);
 //test_num!(parigot, parigot_fac, into_parigot, fac, 0 => 1, 1 => 1, 2 => 2, 3 => 6);
 //test_num!(stumpfu, stumpfu_fac, into_stumpfu, fac, 0 => 1, 1 => 1, 2 => 2, 3 => 6);
+
+test_num!(church,  church_fib,  into_church,  fib, 0 => 0, 1 => 1, 2 => 1, 3 => 2, 4 => 3, 5 => 5, 6 => 8);
+
+test_num!(church,  church_gcd,  into_church,  gcd, 12, 18 => 6, 18, 12 => 6, 5, 0 => 5, 0, 5 => 5, 0, 0 => 0, 7, 3 => 1);
+
+#[test]
+fn church_comparisons() {
+    type Comparison = fn(&usize, &usize) -> bool;
+    let comparisons: [(Term, Comparison); 6] = [
+        (church::eq(), usize::eq),
+        (church::neq(), usize::ne),
+        (church::lt(), usize::lt),
+        (church::leq(), usize::le),
+        (church::gt(), usize::gt),
+        (church::geq(), usize::ge),
+    ];
+
+    for (function, comparison) in &comparisons {
+        for m in 0..5 {
+            for n in 0..5 {
+                let result = beta(
+                    app!(function.clone(), m.into_church(), n.into_church()),
+                    NOR,
+                    0,
+                );
+                assert_eq!(bool::from_church(result), Ok(comparison(&m, &n)));
+            }
+        }
+    }
+}
+
+#[test]
+fn church_pow_steps() {
+    let mut term = app!(church::pow(), 2.into_church(), 3.into_church());
+    assert_eq!(term.reduce(NOR, 0), 22);
+    assert_eq!(term, 8.into_church());
+
+    let mut term = app!(church::pow(), 5.into_church(), 0.into_church());
+    assert_eq!(term.reduce(NOR, 0), 7);
+    assert_eq!(term, 1.into_church());
+}
+
+test_num!(church, church_double, into_church, double,
+    0 => 0,
+    1 => 2,
+    3 => 6
+);
+
+test_num!(church, church_half, into_church, half,
+    0 => 0,
+    1 => 0,
+    2 => 1,
+    7 => 3,
+    8 => 4
+);
+
+#[test]
+fn church_collatz() {
+    // the sequence starting at 6: 6, 3, 10, 5, 16, 8, 4, 2, 1
+    let mut n = 6.into_church();
+
+    for expected in [3, 10, 5, 16, 8, 4, 2, 1] {
+        n = beta(app(church::collatz_step(), n), HAP, 0);
+        assert_eq!(n, expected.into_church());
+    }
+}
+
+#[test]
+fn church_rec() {
+    // λm r. ADD m r sums the numbers below n
+    let triangular = app!(
+        church::rec(),
+        0.into_church(),
+        abs!(2, app!(church::add(), Var(2), Var(1)))
+    );
+    // λm r. m yields the predecessor
+    let pred = app!(church::rec(), 0.into_church(), abs!(2, Var(2)));
+
+    for n in 0..6 {
+        assert_eq!(
+            beta(app(triangular.clone(), n.into_church()), HAP, 0),
+            (n * n.saturating_sub(1) / 2).into_church()
+        );
+        assert_eq!(
+            beta(app(pred.clone(), n.into_church()), HAP, 0),
+            n.saturating_sub(1).into_church()
+        );
+    }
+}
+
+#[test]
+fn church_num_digits() {
+    for base in 0..5 {
+        for n in 0..20 {
+            let mut digits = 0;
+            let mut m = n;
+            while m > 0 {
+                // division by zero or one never reaches zero; the unary length is returned
+                m = if base > 1 { m / base } else { m - 1 };
+                digits += 1;
+            }
+
+            assert_eq!(
+                beta(
+                    app!(church::num_digits(), base.into_church(), n.into_church()),
+                    HAP,
+                    0
+                ),
+                digits.into_church()
+            );
+        }
+    }
+}
+
+#[test]
+fn church_parse() {
+    use lambda::data::num::church::numerals::parse;
+    use std::num::IntErrorKind;
+
+    for n in [0, 1, 7, 42] {
+        assert_eq!(parse(&n.to_string()), Ok(n.into_church()));
+    }
+
+    assert_eq!(
+        parse("abc").unwrap_err().kind(),
+        &IntErrorKind::InvalidDigit
+    );
+    assert_eq!(parse("").unwrap_err().kind(), &IntErrorKind::Empty);
+    assert!(parse(" 1").is_err());
+    assert!(parse("1.5").is_err());
+}
+
+#[test]
+fn church_iterate() {
+    use lambda::data::num::church::{add, apply, iterate, succ};
+
+    for n in 0..5 {
+        let expected = (2 * n).into_church();
+        let add_two = app(add(), 2.into_church());
+
+        assert_eq!(
+            beta(
+                app!(iterate(), n.into_church(), add_two.clone(), 0.into_church()),
+                HAP,
+                0
+            ),
+            expected
+        );
+        assert_eq!(
+            beta(apply(&n.into_church(), add_two, 0.into_church()), HAP, 0),
+            expected
+        );
+        assert_eq!(
+            apply(&n.into_church(), succ(), 0.into_church()),
+            app!(n.into_church(), succ(), 0.into_church())
+        );
+    }
+}
+
+#[test]
+fn church_fixed_point_fac_fib() {
+    for n in 0..5 {
+        assert_eq!(
+            beta(app(church::fac_y(), n.into_church()), NOR, 0),
+            beta(app(church::fac(), n.into_church()), HAP, 0)
+        );
+    }
+    for n in 0..7 {
+        assert_eq!(
+            beta(app(church::fib_y(), n.into_church()), NOR, 0),
+            beta(app(church::fib(), n.into_church()), HAP, 0)
+        );
+    }
+}
+
+#[test]
+fn church_ackermann() {
+    for (m, n, result) in [
+        (0, 0, 1),
+        (0, 4, 5),
+        (1, 0, 2),
+        (1, 3, 5),
+        (2, 0, 3),
+        (2, 1, 5),
+    ] {
+        assert_eq!(
+            beta(
+                app!(church::ackermann(), m.into_church(), n.into_church()),
+                HAP,
+                0
+            ),
+            result.into_church()
+        );
+    }
+}
+
+#[test]
+fn church_division_by_zero_diverges() {
+    for function in [church::div(), church::quot(), church::rem()] {
+        let mut term = app!(function, 3.into_church(), 0.into_church());
+        assert_eq!(term.reduce(NOR, 1000), 1000);
+    }
+}
+
+#[test]
+fn church_from_usize_reduced() {
+    for n in 0..=10 {
+        let mut unreduced = church::zero();
+        for _ in 0..n {
+            unreduced = app(church::succ(), unreduced);
+        }
+
+        assert_eq!(church::from_usize_reduced(n), n.into_church());
+        assert_eq!(beta(unreduced.clone(), NOR, 0), n.into_church());
+        // the numerals are only the same after normalization
+        if n > 0 {
+            assert_ne!(unreduced, n.into_church());
+        }
+    }
+}
+
+#[test]
+fn church_pred_steps() {
+    use lambda::data::pair::{fst, pair, snd};
+    use lambda::reduction::beta_counted;
+
+    // PAIR_PRED ≡ λn.FST (n (λp.PAIR (SND p) (SUCC (SND p))) (PAIR ZERO ZERO))
+    let pair_pred = abs(app(
+        fst(),
+        app!(
+            Var(1),
+            abs(app!(
+                pair(),
+                app(snd(), Var(1)),
+                app(church::succ(), app(snd(), Var(1)))
+            )),
+            app!(pair(), church::zero(), church::zero())
+        ),
+    ));
+
+    for n in 1..20 {
+        let (pred, steps) = beta_counted(app(church::pred(), n.into_church()), NOR, 0);
+        let (pair_pred, pair_steps) = beta_counted(app(pair_pred.clone(), n.into_church()), NOR, 0);
+
+        assert_eq!(pred, pair_pred);
+        assert_eq!(steps, 2 * n + 5);
+        assert_eq!(pair_steps, 10 * n + 6);
+    }
+}
+
+#[test]
+fn scott_constant_time_pred() {
+    use lambda::reduction::beta_counted;
+
+    let steps = |n: usize| beta_counted(app(scott::pred(), n.into_scott()), NOR, 0).1;
+
+    assert_eq!(
+        beta_counted(
+            app(scott::pred(), app(scott::succ(), 1.into_scott())),
+            NOR,
+            0
+        )
+        .0,
+        1.into_scott()
+    );
+    for n in 1..20 {
+        assert_eq!(steps(n), steps(1));
+    }
+}
+
+#[test]
+fn scott_from_scott() {
+    for n in 0..10 {
+        assert_eq!(usize::from_scott(n.into_scott()), Ok(n));
+    }
+    assert_eq!(
+        usize::from_scott(beta(
+            app!(scott::mul(), 2.into_scott(), 3.into_scott()),
+            NOR,
+            0
+        )),
+        Ok(6)
+    );
+    assert_eq!(
+        usize::from_scott(1.into_church()),
+        Err(ConversionError::InvalidTerm)
+    );
+    assert_eq!(
+        usize::from_scott(app(scott::succ(), 1.into_scott())),
+        Err(ConversionError::InvalidTerm)
+    );
+}
+
+#[test]
+fn parigot_constant_time_pred() {
+    use lambda::reduction::beta_counted;
+
+    let steps = |n: usize| beta_counted(app(parigot::pred(), n.into_parigot()), NOR, 0).1;
+
+    for n in 1..10 {
+        assert_eq!(steps(n), steps(1));
+    }
+}
+
+#[test]
+fn parigot_rec() {
+    use parigot::{add, rec, succ};
+
+    // λm r. ADD (SUCC m) r sums the numbers from 1 to n
+    let triangular = app!(
+        rec(),
+        0.into_parigot(),
+        abs!(2, app!(add(), app(succ(), Var(2)), Var(1)))
+    );
+
+    for (n, sum) in [(0, 0), (1, 1), (2, 3), (3, 6), (4, 10)] {
+        assert_eq!(
+            beta(app(triangular.clone(), n.into_parigot()), NOR, 0),
+            sum.into_parigot()
+        );
+    }
+}
+
+#[test]
+fn parigot_from_parigot() {
+    for n in 0..10 {
+        assert_eq!(usize::from_parigot(n.into_parigot()), Ok(n));
+    }
+    assert_eq!(
+        usize::from_parigot(beta(
+            app!(parigot::mul(), 2.into_parigot(), 3.into_parigot()),
+            NOR,
+            0
+        )),
+        Ok(6)
+    );
+    assert_eq!(
+        usize::from_parigot(1.into_church()),
+        Err(ConversionError::InvalidTerm)
+    );
+    assert_eq!(
+        usize::from_parigot(app(parigot::succ(), 1.into_parigot())),
+        Err(ConversionError::InvalidTerm)
+    );
+    // the recursion result must match the predecessor
+    assert_eq!(
+        usize::from_parigot(abs!(2, app!(Var(2), 1.into_parigot(), Var(1)))),
+        Err(ConversionError::InvalidTerm)
+    );
+}
+
+#[test]
+fn church_scott_round_trip() {
+    use lambda::reduction::beta_counted;
+
+    for n in 0..10 {
+        let (scott, to_scott_steps) =
+            beta_counted(app(church::to_scott(), n.into_church()), NOR, 0);
+        let (church, to_church_steps) =
+            beta_counted(app(scott::to_church(), scott.clone()), NOR, 0);
+
+        assert_eq!(scott, n.into_scott());
+        assert_eq!(church, n.into_church());
+        assert_eq!(usize::from_scott(scott), Ok(n));
+        assert_eq!(usize::from_church(church), Ok(n));
+
+        // both conversions take a number of steps linear in the converted number
+        assert_eq!(to_scott_steps, n + 3);
+        assert_eq!(to_church_steps, 9 * (n + 1));
+    }
+}
+
+#[test]
+fn church_from_church() {
+    for n in 0..10 {
+        assert_eq!(usize::from_church(n.into_church()), Ok(n));
+    }
+    assert_eq!(
+        usize::from_church(beta(app(church::fac(), 3.into_church()), NOR, 0)),
+        Ok(6)
+    );
+    assert_eq!(
+        usize::from_church(abs!(2, Var(2))),
+        Err(ConversionError::InvalidTerm)
+    );
+    assert_eq!(
+        usize::from_church(app(church::succ(), 1.into_church())),
+        Err(ConversionError::InvalidTerm)
+    );
+
+    assert_eq!(bool::from_church(true.into()), Ok(true));
+    assert_eq!(bool::from_church(false.into()), Ok(false));
+    assert_eq!(
+        bool::from_church(2.into_church()),
+        Err(ConversionError::InvalidTerm)
+    );
+
+    assert_eq!(
+        Option::<usize>::from_church(None::<usize>.into_church()),
+        Ok(None)
+    );
+    assert_eq!(
+        Option::<usize>::from_church(Some(3).into_church()),
+        Ok(Some(3))
+    );
+    assert_eq!(
+        Option::<bool>::from_church(abs!(2, app(Var(1), true.into()))),
+        Ok(Some(true))
+    );
+    assert_eq!(
+        Option::<bool>::from_church(abs!(2, app(Var(2), true.into()))),
+        Err(ConversionError::InvalidTerm)
+    );
+
+    let swapped = beta(
+        app(
+            abs(app!(
+                pair::pair(),
+                app(pair::snd(), Var(1)),
+                app(pair::fst(), Var(1))
+            )),
+            (5, 3).into_church(),
+        ),
+        NOR,
+        0,
+    );
+    assert_eq!(<(usize, usize)>::from_church(swapped), Ok((3, 5)));
+    assert_eq!(
+        <(usize, bool)>::from_church(Term::from((2.into_church(), false.into()))),
+        Ok((2, false))
+    );
+    assert_eq!(
+        <(usize, bool)>::from_church((2, 3).into_church()),
+        Err(ConversionError::InvalidTerm)
+    );
+    assert_eq!(
+        <(usize, usize)>::from_church(2.into_church()),
+        Err(ConversionError::InvalidTerm)
+    );
+}
+
+#[test]
+fn church_from_church_invalid() {
+    use lambda::combinators::Y;
+
+    assert_eq!(usize::from_church(5.into_church()), Ok(5));
+
+    for term in [
+        Var(1),
+        abs(Var(1)),
+        abs!(3, Var(1)),
+        abs!(2, Var(3)),
+        abs!(2, app(Var(1), Var(2))),
+        abs!(2, app(Var(2), app(Var(1), Var(1)))),
+        abs!(2, app!(Var(2), Var(2), Var(1))),
+    ] {
+        assert_eq!(usize::from_church(term), Err(ConversionError::InvalidTerm));
+    }
+
+    // an infinite number can't be reduced to a numeral within a step limit
+    let infinity = beta(app(Y(), church::succ()), NOR, 100);
+    assert_eq!(
+        usize::from_church(infinity),
+        Err(ConversionError::InvalidTerm)
+    );
+}
+
+#[test]
+fn church_char() {
+    for c in ['\0', 'A', 'z', 'λ'] {
+        assert_eq!(c.into_church(), (c as usize).into_church());
+        assert_eq!(char::from_church(c.into_church()), Ok(c));
+    }
+
+    assert_eq!(
+        char::from_church(0xD800.into_church()),
+        Err(ConversionError::InvalidTerm)
+    );
+}
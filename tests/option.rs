@@ -2,7 +2,7 @@
 
 extern crate lambda_calculus as lambda;
 
-use lambda::data::num::church::succ;
+use lambda::data::num::church::{is_zero, succ};
 use lambda::data::option::*;
 use lambda::*;
 
@@ -96,3 +96,63 @@ fn option_and_then() {
         Some(2).into_church()
     );
 }
+
+#[test]
+fn option_flatten() {
+    assert_eq!(beta(app(flatten(), none()), HAP, 0), none());
+    assert_eq!(
+        beta(app(flatten(), Some(None::<usize>).into_church()), HAP, 0),
+        none()
+    );
+    assert_eq!(
+        beta(app(flatten(), Some(Some(3)).into_church()), HAP, 0),
+        Some(3).into_church()
+    );
+    // flattening a mapped option is the same as binding it
+    let some_succ: Term = abs(app(some(), app(succ(), Var(1))));
+    assert_eq!(
+        beta(
+            app(
+                flatten(),
+                app!(map(), some_succ.clone(), Some(1).into_church())
+            ),
+            HAP,
+            0
+        ),
+        beta(app!(and_then(), Some(1).into_church(), some_succ), HAP, 0)
+    );
+}
+
+#[test]
+fn option_filter() {
+    assert_eq!(beta(app!(filter(), is_zero(), none()), HAP, 0), none());
+    assert_eq!(
+        beta(app!(filter(), is_zero(), Some(0).into_church()), HAP, 0),
+        Some(0).into_church()
+    );
+    assert_eq!(
+        beta(app!(filter(), is_zero(), Some(1).into_church()), HAP, 0),
+        none()
+    );
+}
+
+#[test]
+fn option_or() {
+    assert_eq!(beta(app!(or(), none(), none()), HAP, 0), none());
+    assert_eq!(
+        beta(app!(or(), none(), Some(3).into_church()), HAP, 0),
+        Some(3).into_church()
+    );
+    assert_eq!(
+        beta(app!(or(), Some(1).into_church(), none()), HAP, 0),
+        Some(1).into_church()
+    );
+    assert_eq!(
+        beta(
+            app!(or(), Some(1).into_church(), Some(3).into_church()),
+            HAP,
+            0
+        ),
+        Some(1).into_church()
+    );
+}
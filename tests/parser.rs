@@ -0,0 +1,216 @@
+extern crate lambda_calculus as lambda;
+
+use lambda::combinators::*;
+use lambda::*;
+
+fn assert_round_trip(term: &Term) {
+    assert_eq!(parse(&format!("{}", term), Classic).as_ref(), Ok(term));
+    assert_eq!(parse(&format!("{:?}", term), DeBruijn).as_ref(), Ok(term));
+    assert_eq!(parse(&format!("{:#}", term), Classic).as_ref(), Ok(term));
+    assert_eq!(parse(&format!("{:#?}", term), DeBruijn).as_ref(), Ok(term));
+}
+
+/// A minimal linear congruential generator, so that the generated terms are reproducible.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        ((self.0 >> 33) % bound as u64) as usize
+    }
+}
+
+fn random_closed_term(rng: &mut Lcg, depth: usize, size: usize) -> Term {
+    match rng.next(if size == 0 { 1 } else { 3 }) {
+        0 if depth > 0 => Var(rng.next(depth) + 1),
+        1 if depth > 0 => {
+            let lhs_size = rng.next(size);
+            app(
+                random_closed_term(rng, depth, lhs_size),
+                random_closed_term(rng, depth, size - lhs_size - 1),
+            )
+        }
+        _ => abs(random_closed_term(rng, depth + 1, size.saturating_sub(1))),
+    }
+}
+
+#[test]
+fn round_trip_combinators() {
+    for term in &[
+        I(),
+        K(),
+        S(),
+        i(),
+        B(),
+        C(),
+        W(),
+        o(),
+        O(),
+        Y(),
+        Z(),
+        R(),
+        T(),
+        U(),
+    ] {
+        assert_round_trip(term);
+    }
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn round_trip_encodings() {
+    use lambda::data::num::church;
+    use lambda::data::{boolean, pair};
+
+    let terms = vec![
+        boolean::tru(),
+        boolean::fls(),
+        pair::pair(),
+        church::succ(),
+        church::pred(),
+        church::div(),
+        church::fac(),
+        3.into_church(),
+        3.into_scott(),
+        3.into_parigot(),
+        3.into_stumpfu(),
+        3.into_binary(),
+        (-3).into_signed(Church),
+        vec![1.into_church(), 2.into_church()].into_church(),
+        vec![1.into_church(), 2.into_church()].into_scott(),
+        vec![1.into_church(), 2.into_church()].into_pair_list(),
+    ];
+
+    for term in &terms {
+        assert_round_trip(term);
+    }
+}
+
+#[test]
+fn round_trip_random_closed_terms() {
+    let mut rng = Lcg(42);
+
+    for size in 0..200 {
+        assert_round_trip(&random_closed_term(&mut rng, 0, size));
+    }
+}
+
+#[test]
+fn round_trip_ascii() {
+    let mut rng = Lcg(3);
+
+    for size in 0..100 {
+        let term = random_closed_term(&mut rng, 0, size);
+        let ascii = term.to_ascii_string();
+
+        assert!(ascii.is_ascii());
+        assert_eq!(parse(&ascii, Classic), Ok(term));
+    }
+}
+
+#[test]
+fn round_trip_many_binders() {
+    let term = abs!(30, app!(Var(1), Var(26), Var(27), Var(30)));
+
+    let shown = format!("{}", term).replace(lambda::term::LAMBDA, "λ");
+
+    assert!(shown.contains(".λz.λaa.λab.λac.λad.ad e d a"));
+    assert_round_trip(&term);
+}
+
+#[test]
+fn blc_round_trip() {
+    for term in &[I(), Y()] {
+        assert_eq!(parser::from_blc(&term.to_blc()).as_ref(), Ok(term));
+    }
+
+    let mut rng = Lcg(7);
+
+    for size in 0..100 {
+        let term = random_closed_term(&mut rng, 0, size);
+        assert_eq!(parser::from_blc(&term.to_blc()), Ok(term));
+    }
+}
+
+#[test]
+fn blc_self_interpreter() {
+    let interpreter = parse(
+        "(λ1 1)(λλλ1(λλλλ3(λ5(3(λ2(3(λλ3(λ1 2 3)))(4(λ4(λ3 1(2 1))))))(1(2(λ1 2))\
+         (λ4(λ4(λ2(1 4)))5))))(3 3)2)",
+        DeBruijn,
+    )
+    .unwrap();
+    let encoded = interpreter.to_blc();
+
+    assert_eq!(encoded.len(), 206);
+    assert_eq!(parser::from_blc(&encoded), Ok(interpreter));
+}
+
+#[test]
+fn parse_partial_truncated() {
+    use lambda::parser::{parse_partial, ParseError};
+
+    assert_eq!(
+        parse_partial("λλλ3 1(2 1)", DeBruijn),
+        (Ok(S()), "λλλ3 1(2 1)".len())
+    );
+    assert_eq!(
+        parse_partial("λλλ3 1(2 1", DeBruijn),
+        (Ok(abs!(3, app(Var(3), Var(1)))), "λλλ3 1".len())
+    );
+    assert_eq!(
+        parse_partial("λx.λy.x y)", Classic),
+        (parse("λx.λy.x y", Classic), "λx.λy.x y".len())
+    );
+    assert_eq!(
+        parse_partial("λ 12 (", DeBruijn),
+        (Ok(abs(Var(12))), "λ 12".len())
+    );
+    assert_eq!(
+        parse_partial("λx.xy z", Classic),
+        (Err(ParseError::FreeVariable("xy".into())), "λx.".len())
+    );
+    assert_eq!(
+        parse_partial("(λ1 ())", DeBruijn),
+        (Err(ParseError::EmptyExpression), "(λ1 (".len())
+    );
+    assert_eq!(
+        parse_partial("(λ1 x)", DeBruijn),
+        (
+            Err(ParseError::InvalidCharacter {
+                line: 1,
+                column: 5,
+                character: 'x'
+            }),
+            "(λ1 ".len()
+        )
+    );
+    assert_eq!(
+        parse_partial("λ1 {- 2", DeBruijn),
+        (Ok(abs(Var(1))), "λ1".len())
+    );
+    assert_eq!(
+        parse_partial("λx.(λ", Classic),
+        (Err(ParseError::UnbalancedParens), "λx.(λ".len())
+    );
+
+    let mut rng = Lcg(11);
+
+    for size in 1..100 {
+        let shown = format!("{:?}", random_closed_term(&mut rng, 0, size));
+        let truncated = &shown[..shown
+            .char_indices()
+            .nth(shown.chars().count() / 2)
+            .unwrap()
+            .0];
+
+        let (result, offset) = parse_partial(truncated, DeBruijn);
+        assert!(offset <= truncated.len());
+        if result.is_ok() {
+            assert_eq!(result, parse(&truncated[..offset], DeBruijn));
+        }
+    }
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "rand")]
+
+extern crate lambda_calculus as lambda;
+
+use lambda::term::random::random_closed;
+use lambda::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn random_closed_terms() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for depth in 2..12 {
+        for _ in 0..20 {
+            let term = random_closed(depth, &mut rng);
+
+            assert!(term.is_closed());
+            assert!(term.depth() <= depth);
+        }
+    }
+}
+
+#[test]
+fn random_closed_round_trip() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    for _ in 0..100 {
+        let term = random_closed(12, &mut rng);
+
+        assert_eq!(parse(&term.to_string(), Classic), Ok(term.clone()));
+        assert_eq!(parse(&format!("{:?}", term), DeBruijn), Ok(term));
+    }
+}
+
+#[test]
+#[should_panic]
+fn random_closed_too_shallow() {
+    random_closed(1, &mut StdRng::seed_from_u64(2));
+}
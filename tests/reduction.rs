@@ -1,18 +1,19 @@
 extern crate lambda_calculus as lambda;
 
-use lambda::combinators::{I, O};
+use lambda::combinators::{B, I, K, O, S};
+use lambda::reduction::beta_counted;
 use lambda::*;
 use std::thread;
 
 #[test]
 fn reduction_nor() {
-    let reduces_instantly = parse("(λλ1)((λλλ((32)1))(λλ2))", DeBruijn).unwrap();
+    let reduces_instantly = parse("(λλ1)((λλλ((3 2)1))(λλ2))", DeBruijn).unwrap();
     assert_eq!(
         beta(reduces_instantly.clone(), NOR, 0),
         beta(reduces_instantly, NOR, 1)
     );
 
-    let should_reduce = parse("(λ2)((λ111)(λ111))", DeBruijn).unwrap();
+    let should_reduce = parse("(λ2)((λ1 1 1)(λ1 1 1))", DeBruijn).unwrap();
     assert_eq!(beta(should_reduce, NOR, 0), Var(1));
 
     let does_reduce = app(abs(Var(2)), O());
@@ -37,6 +38,18 @@ fn reduction_app() {
     assert_eq!(wont_reduce, app(abs(Var(2)), O()));
 }
 
+#[test]
+fn reduction_app_vs_nor() {
+    // K I Ω: the discarded argument has no normal form
+    let expr = app!(K(), I(), O());
+
+    assert_eq!(beta(expr.clone(), NOR, 10), I());
+
+    let mut applicative = expr;
+    assert_eq!(applicative.reduce(APP, 10), 10);
+    assert_ne!(applicative, I());
+}
+
 #[test]
 fn reduction_cbv() {
     let mut expr = app(abs(app(I(), Var(1))), app(I(), I()));
@@ -48,6 +61,675 @@ fn reduction_cbv() {
     assert_eq!(expr, I());
 }
 
+#[test]
+fn reduction_cbn_vs_cbv() {
+    // (λx.λy.x) Ω
+    let expr = app(K(), O());
+
+    let mut by_name = expr.clone();
+    assert_eq!(by_name.reduce(CBN, 10), 1);
+    assert_eq!(by_name, abs(O())); // the body of the abstraction is not reduced
+
+    let mut by_value = expr;
+    assert_eq!(by_value.reduce(CBV, 10), 10);
+    assert_eq!(by_value, app(K(), O()));
+}
+
+#[test]
+fn reduction_weak_normal_forms() {
+    let under_abstraction = abs(app(I(), Var(1)));
+
+    assert_eq!(beta(under_abstraction.clone(), CBN, 0), under_abstraction);
+    assert_eq!(beta(under_abstraction.clone(), CBV, 0), under_abstraction);
+    assert_eq!(beta(under_abstraction, NOR, 0), I());
+}
+
+#[test]
+fn reduction_whnf() {
+    use lambda::reduction::whnf;
+
+    // a variable in head position; its argument is not reduced
+    let neutral = abs(app(Var(1), app(I(), Var(1))));
+    assert_eq!(whnf(app(I(), neutral.clone()), 0), neutral);
+    assert_eq!(
+        whnf(app(Var(1), app(I(), I())), 0),
+        app(Var(1), app(I(), I()))
+    );
+
+    // the head redex is contracted until the head is no longer a redex
+    assert_eq!(whnf(app!(K(), I(), O()), 0), I());
+}
+
+#[test]
+fn reduction_count() {
+    for order in [NOR, CBN, HSP, HNO, APP, CBV, HAP] {
+        assert_eq!(beta_counted(app(I(), I()), order, 0), (I(), 1));
+        assert_eq!(beta_counted(I(), order, 0), (I(), 0));
+    }
+}
+
+#[test]
+fn reduction_trace() {
+    use lambda::reduction::beta_trace;
+
+    let expr = parse("(λ2)((λ1 1 1)(λ1 1 1))", DeBruijn).unwrap();
+
+    for order in [NOR, CBN, HSP, HNO, APP, CBV, HAP] {
+        let mut steps = Vec::new();
+        let reduced = beta_trace(expr.clone(), order, 5, |term, step| {
+            steps.push((term.clone(), step))
+        });
+        let (expected, count) = beta_counted(expr.clone(), order, 5);
+
+        assert_eq!(reduced, expected);
+        assert_eq!(steps.len(), count + 1);
+        assert_eq!(steps[0], (expr.clone(), 0));
+        assert_eq!(steps.last(), Some(&(expected, count)));
+
+        for (i, (term, step)) in steps.iter().enumerate().skip(1) {
+            assert_eq!(*step, i);
+            assert_eq!(beta(steps[i - 1].0.clone(), order, 1), *term);
+        }
+    }
+}
+
+#[test]
+fn reduction_eta() {
+    use lambda::reduction::eta;
+
+    // λ f 1 → f
+    assert_eq!(eta(abs(app(Var(2), Var(1))), 0), Var(1));
+    // λ 1 1 is not an η-redex
+    assert_eq!(eta(abs(app(Var(1), Var(1))), 0), abs(app(Var(1), Var(1))));
+    // λ (λ 1) 1 → λ 1, but in λ (λ 2) 1 the variable occurs in the function
+    assert_eq!(eta(abs(app(abs(Var(1)), Var(1))), 0), abs(Var(1)));
+    assert_eq!(
+        eta(abs(app(abs(Var(2)), Var(1))), 0),
+        abs(app(abs(Var(2)), Var(1)))
+    );
+    // λ (λ 1 2) 1 is not an η-redex, but λ 1 2 contains none either
+    assert_eq!(
+        eta(abs(app(abs(app(Var(1), Var(2))), Var(1))), 0),
+        abs(app(abs(app(Var(1), Var(2))), Var(1)))
+    );
+    // free variables are shifted: λ λ 3 4 1 → λ 2 3
+    assert_eq!(
+        eta(abs!(2, app!(Var(3), Var(4), Var(1))), 0),
+        abs(app(Var(2), Var(3)))
+    );
+    // nested redexes
+    assert_eq!(eta(abs!(2, app!(Var(3), Var(2), Var(1))), 0), Var(1));
+    assert_eq!(
+        eta(abs!(2, app!(Var(3), Var(2), Var(1))), 1),
+        abs(app(Var(2), Var(1)))
+    );
+}
+
+#[test]
+fn reduction_beta_eta() {
+    use lambda::reduction::beta_eta;
+
+    // λx.S x
+    let wrapped = abs(app(S(), Var(1)));
+    assert_eq!(beta(wrapped.clone(), NOR, 0), S());
+    assert_eq!(beta_eta(wrapped, NOR, 0), S());
+
+    // λf.λx.B f I x reduces to λf.λx.f x, which is an η-redex
+    let expr = abs!(2, app!(B(), Var(2), I(), Var(1)));
+    assert_eq!(beta(expr.clone(), NOR, 0), abs!(2, app(Var(2), Var(1))));
+    assert_eq!(beta_eta(expr.clone(), NOR, 0), I());
+    assert_eq!(beta_eta(expr, HAP, 0), I());
+
+    // the limit covers both kinds of reductions
+    let expr = abs(app(abs(app(I(), Var(1))), Var(1)));
+    assert_eq!(beta_eta(expr.clone(), NOR, 1), abs(app(I(), Var(1))));
+    assert_eq!(beta_eta(expr.clone(), NOR, 2), abs(Var(1)));
+    assert_eq!(beta_eta(expr, NOR, 0), I());
+}
+
+#[test]
+fn reduction_single_steps() {
+    let expr = parse("(λ1 1)((λλ2 1)(λ1)(λ1))", DeBruijn).unwrap();
+
+    for order in [NOR, CBN, HSP, HNO, APP, CBV, HAP] {
+        let (normal, count) = beta_counted(expr.clone(), order, 0);
+        let mut stepped = expr.clone();
+        let mut steps = 0;
+
+        while stepped.reduce(order, 1) == 1 {
+            steps += 1;
+        }
+
+        assert_eq!(stepped, normal);
+        assert_eq!(steps, count);
+    }
+}
+
+#[test]
+fn reduction_bounded() {
+    use lambda::reduction::{beta_bounded, ReductionError};
+
+    let growing = parse("(λ1 1 1)(λ1 1 1)", DeBruijn).unwrap();
+
+    for order in [NOR, CBN, HSP, HNO, APP, CBV, HAP] {
+        assert_eq!(
+            beta_bounded(growing.clone(), order, 0, 1000),
+            Err(ReductionError::SizeExceeded)
+        );
+        assert_eq!(beta_bounded(app(I(), I()), order, 0, 5), Ok(I()));
+    }
+
+    // the size of the initial term is checked as well
+    assert_eq!(
+        beta_bounded(app(I(), I()), NOR, 0, 4),
+        Err(ReductionError::SizeExceeded)
+    );
+    // the step limit still applies
+    assert_eq!(beta_bounded(O(), NOR, 10, 100), Ok(O()));
+    assert_eq!(
+        beta_bounded(growing.clone(), NOR, 1, 100),
+        Ok(beta(growing, NOR, 1))
+    );
+
+    // the tracked size matches the largest intermediate term exactly
+    let terms = [
+        parse("λ1(λλλ3(λ3(2 1))(λλ2(3 2 1)))(λλ2)(λλ2 1)(λλ2 1)", DeBruijn).unwrap(),
+        parse("(λλ1 2 2)((λ1)(λλ2 1))", DeBruijn).unwrap(),
+        app!(S(), K(), K(), abs(app(Var(1), Var(1)))),
+    ];
+    for term in &terms {
+        for order in [NOR, CBN, HSP, HNO, APP, CBV, HAP] {
+            let mut largest = 0;
+            let normal = lambda::reduction::beta_trace(term.clone(), order, 0, |term, _| {
+                largest = largest.max(term.size())
+            });
+
+            assert_eq!(beta_bounded(term.clone(), order, 0, largest), Ok(normal));
+            assert_eq!(
+                beta_bounded(term.clone(), order, 0, largest - 1),
+                Err(ReductionError::SizeExceeded)
+            );
+        }
+    }
+}
+
+#[test]
+fn reduction_shared() {
+    use lambda::reduction::normalize_shared;
+
+    let terms = [
+        parse("(λ2)((λ1 1 1)(λ1 1 1))", DeBruijn).unwrap(),
+        parse("(λ1 1)((λλ2 1)(λ1)(λ1))", DeBruijn).unwrap(),
+        parse("λ(λλ3 2 1)(λ2 1)", DeBruijn).unwrap(),
+        parse("(λλλ3 1(2 1))(λλ2)(λλ1)", DeBruijn).unwrap(),
+        parse("λ1(λλλ3(λ3(2 1))(λλ2(3 2 1)))(λλ2)(λλ2 1)(λλ2 1)", DeBruijn).unwrap(),
+        app!(K(), I(), O()),
+        app!(S(), K(), K(), Var(1)),
+    ];
+
+    for term in &terms {
+        assert_eq!(
+            normalize_shared(term.clone(), 0),
+            beta(term.clone(), NOR, 0)
+        );
+
+        for limit in 1..10 {
+            assert_eq!(
+                normalize_shared(term.clone(), limit),
+                beta(term.clone(), NOR, limit)
+            );
+        }
+    }
+
+    assert_eq!(normalize_shared(O(), 10), O());
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn reduction_memoized() {
+    use lambda::combinators::Y;
+    use lambda::data::num::church::{add, fac, is_zero, one, pred, zero};
+    use lambda::reduction::normalize_memoized;
+
+    // FIB ≡ Y (λf.λn.IS_ZERO n ZERO (IS_ZERO (PRED n) ONE (ADD (f (PRED n)) (f (PRED (PRED n))))))
+    let fib = app(
+        Y(),
+        abs!(
+            2,
+            app!(
+                is_zero(),
+                Var(1),
+                zero(),
+                app!(
+                    is_zero(),
+                    app(pred(), Var(1)),
+                    one(),
+                    app!(
+                        add(),
+                        app(Var(2), app(pred(), Var(1))),
+                        app(Var(2), app(pred(), app(pred(), Var(1))))
+                    )
+                )
+            )
+        ),
+    );
+
+    let terms = [
+        parse("(λ2)((λ1 1 1)(λ1 1 1))", DeBruijn).unwrap(),
+        parse("(λ1 1)((λλ2 1)(λ1)(λ1))", DeBruijn).unwrap(),
+        parse("λ(λλ3 2 1)(λ2 1)", DeBruijn).unwrap(),
+        parse("λ1(λλλ3(λ3(2 1))(λλ2(3 2 1)))(λλ2)(λλ2 1)(λλ2 1)", DeBruijn).unwrap(),
+        app!(K(), I(), O()),
+        app!(S(), K(), K(), Var(1)),
+        app(fac(), 3.into_church()),
+        app(fib.clone(), 6.into_church()),
+    ];
+
+    for term in &terms {
+        assert_eq!(
+            normalize_memoized(term.clone(), 0),
+            beta(term.clone(), NOR, 0)
+        );
+    }
+
+    assert_eq!(
+        normalize_memoized(app(fib, 10.into_church()), 0),
+        55.into_church()
+    );
+    assert_eq!(normalize_memoized(O(), 10), O());
+}
+
+#[test]
+fn reduction_parallel_step() {
+    use lambda::reduction::parallel_step;
+
+    let step = |term: &str| parallel_step(parse(term, DeBruijn).unwrap());
+    let term = |term: &str| parse(term, DeBruijn).unwrap();
+
+    // the redexes can be nested in either part of another redex
+    assert_eq!(step("(λ1)((λ1)(λ1))"), term("λ1"));
+    assert_eq!(step("(λ(λ1) 1)(λ1)"), term("λ1"));
+    assert_eq!(step("λ(λ2 1)(λ1)((λ1) 1)"), term("λ1 (λ1) 1"));
+    // new redexes are only contracted by the next step
+    assert_eq!(step("(λ1 1)((λ1)(λ1))"), term("(λ1)(λ1)"));
+    assert_eq!(step("(λ1)(λ1)"), term("λ1"));
+    assert_eq!(parallel_step(O()), O());
+
+    // repeated parallel steps are normalizing
+    for term in [
+        app!(K(), I(), O()),
+        app!(S(), K(), K(), Var(1)),
+        parse("(λλλ3 1(2 1))(λλ2)(λλ1)", DeBruijn).unwrap(),
+    ] {
+        let mut reduced = term.clone();
+        for _ in 0..10 {
+            reduced = parallel_step(reduced);
+        }
+        assert_eq!(reduced, beta(term, NOR, 0));
+    }
+}
+
+#[test]
+fn reduction_next_redex_path() {
+    use lambda::reduction::{next_redex_path, Side};
+
+    fn subterm_mut<'t>(mut term: &'t mut Term, path: &[Side]) -> &'t mut Term {
+        for side in path {
+            term = match side {
+                Side::Left => term.lhs_mut().unwrap(),
+                Side::Right => term.rhs_mut().unwrap(),
+                Side::Body => term.unabs_mut().unwrap(),
+            };
+        }
+        term
+    }
+
+    let term = parse("(λ1 1)((λ1)(λ1))", DeBruijn).unwrap();
+
+    assert_eq!(next_redex_path(&term, NOR), Some(vec![]));
+    assert_eq!(next_redex_path(&term, APP), Some(vec![Side::Right]));
+    assert_eq!(next_redex_path(&I(), NOR), None);
+    assert_eq!(next_redex_path(&Var(1), APP), None);
+
+    let terms = [
+        term,
+        parse("λ(λλ3 2 1)(λ2 1)", DeBruijn).unwrap(),
+        parse("λ1((λ1)(λ1))((λλ1)(λ1 1)λ(λ1)1)", DeBruijn).unwrap(),
+        parse("(λλλ3 1(2 1))(λλ2)((λ1)(λλ1))", DeBruijn).unwrap(),
+        parse("λ1(λλλ3(λ3(2 1))(λλ2(3 2 1)))(λλ2)(λλ2 1)(λλ2 1)", DeBruijn).unwrap(),
+        app!(S(), K(), K(), Var(1)),
+        app!(K(), I(), O()),
+    ];
+
+    // contracting the redex at the returned path is the same as a single reduction step
+    for order in [NOR, CBN, HSP, HNO, APP, CBV, HAP] {
+        for term in &terms {
+            let mut term = term.clone();
+
+            for _ in 0..10 {
+                let stepped = beta(term.clone(), order, 1);
+
+                match next_redex_path(&term, order) {
+                    Some(path) => {
+                        let redex = subterm_mut(&mut term, &path);
+                        let (mut lhs, rhs) = redex.clone().unapp().unwrap();
+                        lhs.apply(&rhs).unwrap();
+                        *redex = lhs;
+
+                        assert_eq!(term, stepped, "{} order", order);
+                    }
+                    None => {
+                        assert_eq!(term, stepped, "{} order", order);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn reduction_is_normal() {
+    use lambda::reduction::is_normal;
+
+    let term = parse("λ(λ1)1", DeBruijn).unwrap();
+
+    for order in [CBN, CBV] {
+        assert!(is_normal(&term, order));
+    }
+    for order in [NOR, HSP, HNO, APP, HAP] {
+        assert!(!is_normal(&term, order));
+    }
+
+    // a head normal form with a redex in an argument
+    let term = parse("λ1((λ1)1)", DeBruijn).unwrap();
+    assert!(is_normal(&term, HSP));
+    assert!(!is_normal(&term, NOR));
+
+    // a weak normal form but not a weak head normal form
+    let term = parse("1((λ1)1)", DeBruijn).unwrap();
+    assert!(is_normal(&term, CBN));
+    assert!(!is_normal(&term, CBV));
+
+    for order in [NOR, CBN, HSP, HNO, APP, CBV, HAP] {
+        assert!(is_normal(&I(), order));
+        assert!(!is_normal(&O(), order));
+        assert!(is_normal(&beta(app!(S(), K(), K()), order, 0), order));
+    }
+}
+
+#[test]
+fn reduction_beta_eq() {
+    use lambda::reduction::beta_eq;
+
+    let term = parse("(λ1)(λ1)", DeBruijn).unwrap();
+    let normal = parse("λ1", DeBruijn).unwrap();
+
+    assert_ne!(term, normal);
+    assert!(beta_eq(&term, &normal, NOR, 0));
+    assert!(beta_eq(&normal, &term, NOR, 0));
+    assert!(!beta_eq(&term, &K(), NOR, 0));
+
+    // S K K and S K S both reduce to the identity
+    assert!(beta_eq(&app!(S(), K(), K()), &app!(S(), K(), S()), NOR, 0));
+    assert!(beta_eq(&app!(S(), K(), K()), &I(), NOR, 0));
+    assert!(!beta_eq(&app!(S(), K(), K()), &K(), NOR, 0));
+    assert!(beta_eq(&app!(S(), K(), K(), Var(1)), &Var(1), NOR, 0));
+
+    // the limit applies to each of the terms
+    assert!(beta_eq(&term, &app(I(), term.clone()), NOR, 2));
+    assert!(!beta_eq(&term, &app(I(), term.clone()), NOR, 1));
+
+    // terms without a normal form are only equal to themselves
+    assert!(beta_eq(&O(), &O(), NOR, 10));
+    assert!(!beta_eq(&O(), &app(I(), O()), NOR, 10));
+    assert!(!beta_eq(&app!(K(), I(), O()), &I(), APP, 10));
+    assert!(beta_eq(&app!(K(), I(), O()), &I(), NOR, 10));
+}
+
+#[test]
+fn reduction_confluence() {
+    use lambda::combinators::*;
+    use lambda::reduction::assert_confluent;
+
+    for term in [
+        app!(S(), K(), K()),
+        app!(S(), K(), S(), Var(1)),
+        app!(B(), C(), W(), I()),
+        app!(K(), I(), O()),
+        app!(C(), K(), O(), I()),
+        app(Y(), K()),
+        app(U(), U()),
+        parse("(λλλ3 1(2 1))(λλ2)(λλ1)", DeBruijn).unwrap(),
+        parse("λ1(λλλ3(λ3(2 1))(λλ2(3 2 1)))(λλ2)(λλ2 1)(λλ2 1)", DeBruijn).unwrap(),
+    ] {
+        assert_confluent(&term, 100);
+    }
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn reduction_confluence_encodings() {
+    use lambda::data::num::church;
+    use lambda::reduction::assert_confluent;
+
+    for term in [
+        app!(church::add(), 2.into_church(), 3.into_church()),
+        app!(church::mul(), 2.into_church(), 3.into_church()),
+        app!(church::pow(), 2.into_church(), 3.into_church()),
+        app!(church::sub(), 5.into_church(), 2.into_church()),
+        app(church::pred(), 3.into_church()),
+        app(church::fac(), 3.into_church()),
+        app(church::is_even(), 3.into_church()),
+        app(church::to_scott(), 3.into_church()),
+    ] {
+        assert_confluent(&term, 10_000);
+    }
+}
+
+#[test]
+fn reduction_limit_semantics() {
+    // (λ1)((λ1)((λ1)(λ1))) takes 3 steps to reach λ1
+    let term = parse("(λ1)((λ1)((λ1)(λ1)))", DeBruijn).unwrap();
+    let normal = parse("λ1", DeBruijn).unwrap();
+
+    for order in [NOR, CBN, HSP, HNO, APP, CBV, HAP] {
+        // 0 means no limit
+        assert_eq!(beta_counted(term.clone(), order, 0), (normal.clone(), 3));
+
+        // a positive limit caps the number of steps
+        for limit in 1..3 {
+            let (reduced, steps) = beta_counted(term.clone(), order, limit);
+
+            assert_eq!(steps, limit);
+            assert_ne!(reduced, normal);
+        }
+        for limit in 3..6 {
+            assert_eq!(
+                beta_counted(term.clone(), order, limit),
+                (normal.clone(), 3)
+            );
+        }
+    }
+
+    assert_eq!(beta_counted(O(), NOR, 7), (O(), 7));
+}
+
+#[test]
+fn reduction_try_normalize() {
+    use lambda::reduction::{try_normalize, ReductionError};
+
+    let growing = parse("(λ1 1 1)(λ1 1 1)", DeBruijn).unwrap();
+
+    for order in [NOR, CBN, HSP, HNO, APP, CBV, HAP] {
+        assert_eq!(try_normalize(O(), order, 0), Err(ReductionError::Diverges));
+        assert_eq!(
+            try_normalize(O(), order, 100),
+            Err(ReductionError::Diverges)
+        );
+        assert_eq!(
+            try_normalize(growing.clone(), order, 100),
+            Err(ReductionError::LimitReached)
+        );
+        assert_eq!(try_normalize(app(I(), I()), order, 0), Ok(I()));
+        assert_eq!(try_normalize(I(), order, 1), Ok(I()));
+    }
+
+    // the discarded argument doesn't matter in the normal order
+    assert_eq!(try_normalize(app!(K(), I(), O()), NOR, 0), Ok(I()));
+    assert_eq!(
+        try_normalize(app!(K(), I(), O()), APP, 0),
+        Err(ReductionError::Diverges)
+    );
+}
+
+#[test]
+fn reduction_shift() {
+    // λ λ 1 2 (3 4) (λ 4)
+    let mut term = abs!(2, app!(Var(1), Var(2), app(Var(3), Var(4)), abs(Var(4))));
+
+    term.shift(2, 0);
+    assert_eq!(
+        term,
+        abs!(2, app!(Var(1), Var(2), app(Var(5), Var(6)), abs(Var(6))))
+    );
+
+    term.shift(-1, 1);
+    assert_eq!(
+        term,
+        abs!(2, app!(Var(1), Var(2), app(Var(4), Var(5)), abs(Var(5))))
+    );
+
+    term.shift(-2, 0);
+    assert_eq!(
+        term,
+        abs!(2, app!(Var(1), Var(2), app(Var(2), Var(3)), abs(Var(3))))
+    );
+}
+
+#[test]
+#[should_panic]
+fn reduction_shift_below_one() {
+    Var(1).shift(-1, 0);
+}
+
+#[test]
+fn reduction_substitute() {
+    // λ 2 (λ 3 1 2)
+    let mut term = abs(app(Var(2), abs(app!(Var(3), Var(1), Var(2)))));
+
+    term.substitute(1, &app(Var(1), abs(Var(2))));
+    assert_eq!(
+        term,
+        abs(app(
+            app(Var(2), abs(Var(3))),
+            abs(app!(app(Var(3), abs(Var(4))), Var(1), Var(2)))
+        ))
+    );
+
+    // contracting a redex is substitution followed by a shift
+    for (body, arg) in [
+        (app(Var(1), Var(2)), Var(3)),
+        (abs(app!(Var(2), Var(1), Var(3))), abs(app(Var(1), Var(2)))),
+        (app(K(), Var(1)), S()),
+    ] {
+        let mut substituted = body.clone();
+        let mut shifted_arg = arg.clone();
+        shifted_arg.shift(1, 0);
+        substituted.substitute(1, &shifted_arg);
+        substituted.shift(-1, 0);
+
+        assert_eq!(beta(app(abs(body), arg), NOR, 1), substituted);
+    }
+}
+
+#[test]
+fn reduction_redex_count() {
+    assert_eq!(app(I(), I()).redex_count(), 1);
+    assert_eq!(I().redex_count(), 0);
+    assert_eq!(O().redex_count(), 1);
+    assert_eq!(
+        app!(I(), app(I(), I()), abs(app(I(), Var(1)))).redex_count(),
+        3
+    );
+    assert_eq!(app!(Var(1), I(), I()).redex_count(), 0);
+
+    for term in [
+        app(I(), I()),
+        app!(S(), K(), K(), Var(1)),
+        app!(B(), I(), I()),
+    ] {
+        assert!(!term.is_normal_form());
+        assert!(beta(term, NOR, 0).is_normal_form());
+    }
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn reduction_theta_strategies() {
+    use lambda::combinators::{T, Y};
+    use lambda::data::num::church::{is_zero, mul, pred};
+    use lambda::reduction::{try_normalize, ReductionError};
+
+    // FAC ≡ λfn.IS_ZERO n ONE (MUL n (f (PRED n)))
+    let fac = abs!(
+        2,
+        app!(
+            is_zero(),
+            Var(1),
+            1.into_church(),
+            app!(mul(), Var(1), app(Var(2), app(pred(), Var(1))))
+        )
+    );
+
+    for fix in [T(), Y()] {
+        for order in [NOR, HNO] {
+            assert_eq!(
+                try_normalize(app!(fix.clone(), fac.clone(), 3.into_church()), order, 0),
+                Ok(6.into_church())
+            );
+        }
+        for order in [APP, CBV, HAP] {
+            assert_eq!(
+                try_normalize(app!(fix.clone(), fac.clone(), 3.into_church()), order, 100),
+                Err(ReductionError::LimitReached)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn reduction_z_call_by_value() {
+    use lambda::combinators::{Y, Z};
+    use lambda::data::num::church::{is_zero, mul, pred};
+    use lambda::reduction::{try_normalize, ReductionError};
+
+    // FAC ≡ λfn.IS_ZERO n (λx.ONE) (λx.MUL n (f (PRED n))) I; the branches are delayed
+    let fac = abs!(
+        2,
+        app!(
+            is_zero(),
+            Var(1),
+            abs(1.into_church()),
+            abs(app!(mul(), Var(2), app(Var(3), app(pred(), Var(2))))),
+            I()
+        )
+    );
+
+    let z_fac = beta(app!(Z(), fac.clone(), 3.into_church()), CBV, 0);
+    assert_eq!(beta(z_fac, NOR, 0), 6.into_church());
+    assert_eq!(
+        beta(app!(Z(), fac.clone(), 3.into_church()), HAP, 0),
+        6.into_church()
+    );
+
+    assert_eq!(
+        try_normalize(app!(Y(), fac, 3.into_church()), CBV, 100),
+        Err(ReductionError::LimitReached)
+    );
+}
+
 #[test]
 #[ignore]
 fn reduction_huge() {
@@ -55,8 +737,8 @@ fn reduction_huge() {
         .name("reductor".into())
         .stack_size(1024 * 1024 * 1024);
 
-    let factorial = parse("λ1(λλλ3(λ3(21))(λλ2(321)))(λλ2)(λλ21)(λλ21)", DeBruijn).unwrap();
-    let church_ten = parse("λλ2(2(2(2(2(2(2(2(2(21)))))))))", DeBruijn).unwrap();
+    let factorial = parse("λ1(λλλ3(λ3(2 1))(λλ2(3 2 1)))(λλ2)(λλ2 1)(λλ2 1)", DeBruijn).unwrap();
+    let church_ten = parse("λλ2(2(2(2(2(2(2(2(2(2 1)))))))))", DeBruijn).unwrap();
 
     let handler = builder
         .spawn(|| {
@@ -0,0 +1,44 @@
+#![cfg(feature = "encoding")]
+
+extern crate lambda_calculus as lambda;
+
+use lambda::data::num::church::{is_zero, succ};
+use lambda::data::result::*;
+use lambda::*;
+
+#[test]
+fn result_map_or_else() {
+    let ok_one: Result<usize, usize> = Ok(1);
+    let err_zero: Result<usize, usize> = Err(0);
+
+    assert_eq!(
+        beta(
+            app!(map_or_else(), is_zero(), succ(), ok_one.into_church()),
+            HAP,
+            0
+        ),
+        2.into_church()
+    );
+    assert_eq!(
+        beta(
+            app!(map_or_else(), is_zero(), succ(), err_zero.into_church()),
+            HAP,
+            0
+        ),
+        true.into()
+    );
+}
+
+#[test]
+fn result_round_trip() {
+    for result in [Ok(3), Err(4)] {
+        let result: Result<usize, usize> = result;
+        let rebuilt = app!(map_or_else(), err(), ok(), result.into_church());
+
+        assert_eq!(beta(rebuilt, HAP, 0), result.into_church());
+        assert_eq!(
+            beta(app(is_ok(), result.into_church()), HAP, 0),
+            result.is_ok().into()
+        );
+    }
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "serde")]
+
+extern crate lambda_calculus as lambda;
+
+use lambda::combinators::{I, S, Y};
+use lambda::*;
+
+#[test]
+fn serde_json_format() {
+    assert_eq!(serde_json::to_string(&I()).unwrap(), r#"{"Abs":{"Var":1}}"#);
+    assert_eq!(
+        serde_json::to_string(&app(Var(1), Var(2))).unwrap(),
+        r#"{"App":[{"Var":1},{"Var":2}]}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Term>(r#"{"Abs":{"Abs":{"Var":2}}}"#).unwrap(),
+        abs!(2, Var(2))
+    );
+    assert!(serde_json::from_str::<Term>(r#"{"Var":-1}"#).is_err());
+    assert!(serde_json::from_str::<Term>(r#"{"Lam":{"Var":1}}"#).is_err());
+}
+
+#[test]
+fn serde_round_trip() {
+    for term in &[I(), S(), Y(), UD, app!(Var(3), abs(Var(4)), I())] {
+        let json = serde_json::to_string(term).unwrap();
+        assert_eq!(&serde_json::from_str::<Term>(&json).unwrap(), term);
+    }
+}
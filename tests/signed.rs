@@ -356,3 +356,46 @@ fn signed_mul() {
         (-6).into_signed(Church)
     );
 }
+
+#[test]
+fn signed_is_zero() {
+    for encoding in [Church, Scott, Parigot, StumpFu] {
+        for n in -2..3 {
+            assert_eq!(
+                beta(app(is_zero(encoding), n.into_signed(encoding)), NOR, 0),
+                (n == 0).into()
+            );
+        }
+    }
+}
+
+#[test]
+fn signed_from_signed() {
+    for encoding in [Church, Scott] {
+        for n in -3i64..4 {
+            assert_eq!(i64::from_signed(n.into_signed(encoding), encoding), Ok(n));
+        }
+    }
+
+    let sum = beta(
+        app!(
+            add(Church),
+            3i64.into_signed(Church),
+            (-5i64).into_signed(Church)
+        ),
+        NOR,
+        0,
+    );
+    assert_eq!(sum, (-2i64).into_signed(Church));
+    assert_eq!(i32::from_signed(sum, Church), Ok(-2));
+
+    assert_eq!(i32::from_signed((4, 6).into_church(), Church), Ok(-2));
+    assert_eq!(
+        i32::from_signed(3.into_church(), Church),
+        Err(ConversionError::InvalidTerm)
+    );
+    assert_eq!(
+        i32::from_signed((1, 2).into_scott(), Church),
+        Err(ConversionError::InvalidTerm)
+    );
+}